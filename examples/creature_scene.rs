@@ -0,0 +1,71 @@
+// The creature on its own, dropped into a scene that isn't the game: no menus, food or
+// score, just a field of pebbles to swim around and a camera that follows along.
+//
+//     cargo run --example creature_scene
+//
+// Arrow keys swim, "1" shows the debug gizmos.
+
+use bevy::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+
+use sylvester::model::game_model::{AppState, PlayState};
+use sylvester::snake_model::SnakeModel;
+use sylvester::CreaturePlugins;
+
+const BACKGROUND_COLOR: Color = Color::srgb(0.05, 0.12, 0.1);
+const PEBBLE_COUNT: usize = 120;
+const PEBBLE_FIELD_RADIUS: f32 = 1500.0;
+// how quickly the camera catches up with the head, per second
+const CAMERA_FOLLOW_RATE: f32 = 3.0;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .insert_resource(ClearColor(BACKGROUND_COLOR))
+        // the creature spawns on entering Playing, so start there instead of a main menu
+        .insert_state(AppState::Playing)
+        .add_sub_state::<PlayState>()
+        .add_plugins(CreaturePlugins)
+        .add_systems(Startup, setup_scene)
+        .add_systems(Update, camera_follow)
+        .run();
+}
+
+fn setup_scene(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
+
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..PEBBLE_COUNT {
+        let pos = Vec2::new(
+            rng.gen_range(-PEBBLE_FIELD_RADIUS..=PEBBLE_FIELD_RADIUS),
+            rng.gen_range(-PEBBLE_FIELD_RADIUS..=PEBBLE_FIELD_RADIUS),
+        );
+        let size = rng.gen_range(6.0..=28.0);
+        let shade = rng.gen_range(0.15..=0.3);
+        commands.spawn(SpriteBundle {
+            sprite: Sprite {
+                color: Color::srgb(shade, shade + 0.05, shade),
+                custom_size: Some(Vec2::splat(size)),
+                ..default()
+            },
+            // below the creature's sprites
+            transform: Transform::from_translation(pos.extend(-1.0))
+                .with_rotation(Quat::from_rotation_z(rng.gen_range(0.0..std::f32::consts::TAU))),
+            ..default()
+        });
+    }
+}
+
+fn camera_follow(
+    snake_query: Query<&SnakeModel>,
+    mut camera_query: Query<&mut Transform, With<Camera>>,
+    time: Res<Time>,
+) {
+    let Some(snake) = snake_query.iter().next() else { return; };
+    let t = (CAMERA_FOLLOW_RATE * time.delta_seconds()).min(1.0);
+    for mut transform in &mut camera_query {
+        let pos = transform.translation.truncate().lerp(snake.head_pos, t);
+        transform.translation = pos.extend(transform.translation.z);
+    }
+}
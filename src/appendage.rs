@@ -0,0 +1,273 @@
+use std::f32::consts::PI;
+use bevy::prelude::*;
+use bevy::sprite::Anchor;
+
+use crate::creature_body_evolution::{CreatureBodyVisualElement, PARKED_SEGMENT_POSITION, SCALE_TRANSITION_DURATION, ease_smoothstep};
+use crate::snake_model::{SnakeModel, SnakeSpineNodeType};
+use crate::game_clock::GameClock;
+
+// Appendages (fins, feelers, spikes) hang off specific body segments and grow in or
+// shrink away as the creature changes evolution tier. Like body segments, their sprites
+// are spawned once up front (parked) and only repositioned/rescaled afterwards.
+
+/// Every appendage sprite has this component, so it can be queried separately from body segments.
+#[derive(Component)]
+pub struct CreatureAppendageVisualElement;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AppendageKind {
+    // sweeps back from the side of the body and flaps when turning
+    Fin,
+    // long and thin, points forward from the head and sways when turning
+    Feeler,
+    // short and rigid, sticks straight out sideways
+    Spike,
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum AppendageSide {
+    Left,
+    Right,
+}
+
+impl AppendageSide {
+    // +1 for left (counter-clockwise from the heading), -1 for right.
+    fn sign(self) -> f32 {
+        match self {
+            AppendageSide::Left => 1.0,
+            AppendageSide::Right => -1.0,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct AppendageSpec {
+    pub kind: AppendageKind,
+    // which snake.body segment it's anchored to (0 = head)
+    pub body_index: usize,
+    pub side: AppendageSide,
+}
+
+// One spawned appendage sprite and how far it has grown in (0 = hidden, 1 = fully shown).
+pub struct Appendage {
+    pub spec: AppendageSpec,
+    pub entity: Entity,
+    pub appear_progress: f32,
+}
+
+const fn pair(kind: AppendageKind, body_index: usize) -> [AppendageSpec; 2] {
+    [
+        AppendageSpec { kind, body_index, side: AppendageSide::Left },
+        AppendageSpec { kind, body_index, side: AppendageSide::Right },
+    ]
+}
+
+const FEELERS: [AppendageSpec; 2] = pair(AppendageKind::Feeler, 0);
+const FRONT_FINS: [AppendageSpec; 2] = pair(AppendageKind::Fin, 2);
+const SPIKES: [AppendageSpec; 2] = pair(AppendageKind::Spike, 5);
+const REAR_FINS: [AppendageSpec; 2] = pair(AppendageKind::Fin, 8);
+
+// Which appendages a creature has at each evolution tier - each tier keeps the
+// previous tier's set and adds to it.
+pub fn appendages_for_tier(tier: SnakeSpineNodeType) -> Vec<AppendageSpec> {
+    let mut specs: Vec<AppendageSpec> = FEELERS.to_vec();
+    if tier != SnakeSpineNodeType::Small {
+        specs.extend(FRONT_FINS);
+    }
+    if tier == SnakeSpineNodeType::Big {
+        specs.extend(SPIKES);
+        specs.extend(REAR_FINS);
+    }
+    specs
+}
+
+// Every appendage any tier can show - the set spawned (parked) up front.
+fn all_appendage_specs() -> Vec<AppendageSpec> {
+    appendages_for_tier(SnakeSpineNodeType::Big)
+}
+
+// Visible at this tier and anchored to a segment the creature currently has.
+fn appendage_is_wanted(spec: &AppendageSpec, tier: SnakeSpineNodeType, size: f32) -> bool {
+    spec.body_index <= size as usize && appendages_for_tier(tier).contains(spec)
+}
+
+// Moves appear_progress toward 1 (wanted) or 0 (not wanted) over SCALE_TRANSITION_DURATION,
+// so appendages grow in at the same pace as the tier's node_radius transition.
+pub fn update_appear_progress(appendage: &mut Appendage, wanted: bool, delta_seconds: f32) {
+    let step = delta_seconds / SCALE_TRANSITION_DURATION;
+    appendage.appear_progress = if wanted {
+        (appendage.appear_progress + step).min(1.0)
+    } else {
+        (appendage.appear_progress - step).max(0.0)
+    };
+}
+
+// Angle (radians) the appendage points at relative to its segment's heading. Turning toward
+// one side folds that side's fins in and spreads the other side's out; the faster the
+// turn, the harder the fins beat (in time with the swimming wave's phase).
+const FIN_REST_ANGLE: f32 = PI * 0.75;
+const FEELER_REST_ANGLE: f32 = PI * 0.15;
+const SPIKE_REST_ANGLE: f32 = PI * 0.5;
+// Radians of fin spread per radian/second of turning.
+const TURN_SPREAD_GAIN: f32 = 0.15;
+// Turning rate (radians/second) at which fins beat at full FIN_BEAT_AMPLITUDE.
+const FULL_BEAT_TURN_RATE: f32 = 3.0;
+const FIN_BEAT_AMPLITUDE: f32 = PI * 0.12;
+const MAX_TURN_SPREAD: f32 = PI * 0.2;
+
+pub fn appendage_flap_angle(kind: AppendageKind, side: AppendageSide, turn_rate: f32, phase: f32) -> f32 {
+    let turn_spread = (turn_rate * TURN_SPREAD_GAIN).clamp(-MAX_TURN_SPREAD, MAX_TURN_SPREAD);
+    let beat_strength = (turn_rate.abs() / FULL_BEAT_TURN_RATE).min(1.0);
+    let beat = FIN_BEAT_AMPLITUDE * beat_strength * f32::sin(phase);
+    let side_sign = side.sign();
+    match kind {
+        AppendageKind::Fin => side_sign * (FIN_REST_ANGLE + beat) - turn_spread,
+        AppendageKind::Feeler => side_sign * FEELER_REST_ANGLE + turn_spread * 0.5,
+        AppendageKind::Spike => side_sign * SPIKE_REST_ANGLE,
+    }
+}
+
+// (length, width) of the appendage sprite in multiples of the segment radius.
+fn appendage_size_factors(kind: AppendageKind) -> Vec2 {
+    match kind {
+        AppendageKind::Fin => Vec2::new(2.2, 0.9),
+        AppendageKind::Feeler => Vec2::new(3.0, 0.15),
+        AppendageKind::Spike => Vec2::new(1.0, 0.35),
+    }
+}
+
+fn appendage_color(kind: AppendageKind) -> Color {
+    match kind {
+        AppendageKind::Fin => Color::srgba(0.55, 0.85, 1.0, 0.8),
+        AppendageKind::Feeler => Color::srgba(0.9, 0.9, 0.75, 0.9),
+        AppendageKind::Spike => Color::srgba(1.0, 0.6, 0.45, 0.9),
+    }
+}
+
+// Spawns one parked sprite for every appendage any tier can have. Sprites are plain
+// tinted quads anchored at their base, so rotating them pivots around the body.
+pub fn spawn_appendages(commands: &mut Commands) -> Vec<Appendage> {
+    all_appendage_specs().into_iter().map(|spec| {
+        let entity = commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: appendage_color(spec.kind),
+                    custom_size: Some(Vec2::ONE),
+                    anchor: Anchor::CenterLeft,
+                    ..default()
+                },
+                // slightly behind body segments so the base tucks under the body
+                transform: Transform::from_translation(PARKED_SEGMENT_POSITION - Vec3::Z * 0.1),
+                ..default()
+            },
+            CreatureAppendageVisualElement
+        )).id();
+        Appendage { spec, entity, appear_progress: 0.0 }
+    }).collect()
+}
+
+// Runs after snake_update has placed the body segments for this frame: eases each
+// appendage in/out and pins it to its segment, inheriting that segment's rotation.
+pub fn appendage_update(
+    mut snake_query: Query<&mut SnakeModel>,
+    body_query: Query<&Transform, With<CreatureBodyVisualElement>>,
+    mut appendage_query: Query<&mut Transform, (With<CreatureAppendageVisualElement>, Without<CreatureBodyVisualElement>)>,
+    clock: Res<GameClock>,
+) {
+    for mut snake in &mut snake_query {
+        let snake = &mut *snake;
+        for appendage in &mut snake.appendages {
+            let wanted = appendage_is_wanted(&appendage.spec, snake.evolution_tier, snake.size);
+            update_appear_progress(appendage, wanted, clock.delta_seconds());
+
+            let Ok(mut transform) = appendage_query.get_mut(appendage.entity) else { continue; };
+            let segment = snake.body.get(appendage.spec.body_index);
+            let segment_transform = segment.and_then(|node| body_query.get(node.node_type).ok());
+            let (Some(segment), Some(segment_transform)) = (segment, segment_transform) else { continue; };
+            if appendage.appear_progress <= 0.0 {
+                transform.translation = PARKED_SEGMENT_POSITION - Vec3::Z * 0.1;
+                continue;
+            }
+
+            // body sprites are rotated by (heading + PI/2 + PI), see draw_nodes
+            let (_, _, sprite_angle) = segment_transform.rotation.to_euler(EulerRot::XYZ);
+            let heading = sprite_angle - PI / 2.0 - PI;
+            let side_sign = appendage.spec.side.sign();
+            let anchor = segment_transform.translation.truncate() + Vec2::from_angle(heading + side_sign * PI / 2.0) * segment.radius * 0.8;
+            let angle = heading + appendage_flap_angle(appendage.spec.kind, appendage.spec.side, snake.turn_rate, snake.swim_phase);
+
+            let grown = ease_smoothstep(appendage.appear_progress);
+            let size = appendage_size_factors(appendage.spec.kind) * segment.radius * grown;
+            transform.translation = anchor.extend(segment_transform.translation.z - 0.1);
+            transform.rotation = Quat::from_rotation_z(angle);
+            transform.scale = size.extend(1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_appendage(spec: AppendageSpec) -> Appendage {
+        Appendage { spec, entity: Entity::PLACEHOLDER, appear_progress: 0.0 }
+    }
+
+    #[test]
+    fn each_tier_keeps_the_previous_tiers_appendages() {
+        let small = appendages_for_tier(SnakeSpineNodeType::Small);
+        let medium = appendages_for_tier(SnakeSpineNodeType::Medium);
+        let big = appendages_for_tier(SnakeSpineNodeType::Big);
+        assert!(small.iter().all(|spec| medium.contains(spec)));
+        assert!(medium.iter().all(|spec| big.contains(spec)));
+        assert!(small.len() < medium.len() && medium.len() < big.len());
+    }
+
+    #[test]
+    fn appendage_beyond_the_body_is_not_shown() {
+        let rear_fin = REAR_FINS[0];
+        assert!(!appendage_is_wanted(&rear_fin, SnakeSpineNodeType::Big, rear_fin.body_index as f32 - 1.0));
+        assert!(appendage_is_wanted(&rear_fin, SnakeSpineNodeType::Big, rear_fin.body_index as f32));
+    }
+
+    #[test]
+    fn appendage_grows_in_over_the_transition_duration() {
+        let mut appendage = test_appendage(FRONT_FINS[0]);
+        update_appear_progress(&mut appendage, true, SCALE_TRANSITION_DURATION / 2.0);
+        assert!(appendage.appear_progress > 0.0 && appendage.appear_progress < 1.0);
+        update_appear_progress(&mut appendage, true, SCALE_TRANSITION_DURATION);
+        assert_eq!(appendage.appear_progress, 1.0);
+    }
+
+    #[test]
+    fn appendage_shrinks_away_when_no_longer_wanted() {
+        let mut appendage = test_appendage(FRONT_FINS[0]);
+        appendage.appear_progress = 1.0;
+        update_appear_progress(&mut appendage, false, SCALE_TRANSITION_DURATION * 2.0);
+        assert_eq!(appendage.appear_progress, 0.0);
+    }
+
+    #[test]
+    fn fins_are_mirrored_when_not_turning() {
+        let left = appendage_flap_angle(AppendageKind::Fin, AppendageSide::Left, 0.0, 1.0);
+        let right = appendage_flap_angle(AppendageKind::Fin, AppendageSide::Right, 0.0, 1.0);
+        assert_eq!(left, -right);
+    }
+
+    #[test]
+    fn turning_shifts_both_fins_the_same_way() {
+        let left_straight = appendage_flap_angle(AppendageKind::Fin, AppendageSide::Left, 0.0, 0.0);
+        let left_turning = appendage_flap_angle(AppendageKind::Fin, AppendageSide::Left, 2.0, 0.0);
+        let right_straight = appendage_flap_angle(AppendageKind::Fin, AppendageSide::Right, 0.0, 0.0);
+        let right_turning = appendage_flap_angle(AppendageKind::Fin, AppendageSide::Right, 2.0, 0.0);
+        assert!(left_turning < left_straight);
+        assert!(right_turning < right_straight);
+    }
+
+    #[test]
+    fn spikes_never_flap() {
+        let still = appendage_flap_angle(AppendageKind::Spike, AppendageSide::Left, 0.0, 0.0);
+        let turning = appendage_flap_angle(AppendageKind::Spike, AppendageSide::Left, 5.0, PI / 2.0);
+        assert_eq!(still, turning);
+    }
+}
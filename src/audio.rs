@@ -0,0 +1,196 @@
+use bevy::audio::{PlaybackSettings, Volume};
+use bevy::prelude::*;
+
+use crate::creature_body_evolution::{SCALE_TRANSITION_DURATION, ease_smoothstep, tier_rank};
+use crate::game_flow::despawn_all;
+use crate::gameplay_events::{FoodEaten, FoodKind, PoisonEaten, TierChanged};
+use crate::model::game_model::{AppState, PlayState};
+use crate::settings::Settings;
+use crate::snake_model::SnakeSpineNodeType;
+
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicMix>();
+        app.add_systems(Startup, load_sound_effects);
+        app.add_systems(OnEnter(AppState::Playing), start_music);
+        app.add_systems(Update, (
+            play_sound_effects,
+            music_mix_update.run_if(in_state(PlayState::Running)),
+            music_volume_update.after(music_mix_update).run_if(in_state(AppState::Playing)),
+        ));
+        app.add_systems(OnExit(AppState::Playing), despawn_all::<MusicLayer>);
+    }
+}
+
+// One-shot sounds for gameplay events, and an ambient music bed made of looping layers
+// that all play in sync. Each tier has its own mix of layer gains; when the tier changes
+// the gains crossfade over SCALE_TRANSITION_DURATION, alongside the body and background.
+
+const MUSIC_LAYER_PATHS: [&str; 3] = [
+    "audio/music_layer_0.wav", // warm drone - the Small/Medium bed
+    "audio/music_layer_1.wav", // high shimmer, joins from Medium
+    "audio/music_layer_2.wav", // deep pulse that replaces the drone at Big
+];
+
+#[derive(Resource)]
+struct SoundEffects {
+    eat: Handle<AudioSource>,
+    poison: Handle<AudioSource>,
+    tier_up: Handle<AudioSource>,
+    tier_down: Handle<AudioSource>,
+}
+
+#[derive(Component)]
+struct MusicLayer {
+    index: usize,
+}
+
+// The crossfade in progress between two tiers' layer gains.
+#[derive(Resource)]
+struct MusicMix {
+    from: [f32; 3],
+    tier: SnakeSpineNodeType,
+    elapsed: f32,
+}
+
+impl Default for MusicMix {
+    fn default() -> Self {
+        Self {
+            from: layer_gains(SnakeSpineNodeType::Small),
+            tier: SnakeSpineNodeType::Small,
+            elapsed: SCALE_TRANSITION_DURATION, // starts settled
+        }
+    }
+}
+
+impl MusicMix {
+    fn gains(&self) -> [f32; 3] {
+        crossfade_gains(self.from, layer_gains(self.tier), self.elapsed)
+    }
+}
+
+// How loud each music layer is at a tier, before the player's volume settings.
+pub fn layer_gains(tier: SnakeSpineNodeType) -> [f32; 3] {
+    match tier {
+        SnakeSpineNodeType::Small => [1.0, 0.0, 0.0],
+        SnakeSpineNodeType::Medium => [1.0, 0.8, 0.0],
+        SnakeSpineNodeType::Big => [0.0, 0.8, 1.0],
+    }
+}
+
+// Layer gains `elapsed` seconds into a crossfade from `from` to `to`.
+pub fn crossfade_gains(from: [f32; 3], to: [f32; 3], elapsed: f32) -> [f32; 3] {
+    let t = ease_smoothstep((elapsed / SCALE_TRANSITION_DURATION).clamp(0.0, 1.0));
+    std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+}
+
+pub fn music_volume(settings: &Settings, layer_gain: f32) -> f32 {
+    settings.master_volume * settings.music_volume * layer_gain
+}
+
+pub fn sfx_volume(settings: &Settings) -> f32 {
+    settings.master_volume * settings.sfx_volume
+}
+
+fn load_sound_effects(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SoundEffects {
+        eat: asset_server.load("audio/eat.wav"),
+        poison: asset_server.load("audio/poison.wav"),
+        tier_up: asset_server.load("audio/tier_up.wav"),
+        tier_down: asset_server.load("audio/tier_down.wav"),
+    });
+}
+
+// Every run starts at Small, so the bed starts on Small's mix.
+fn start_music(mut commands: Commands, asset_server: Res<AssetServer>, mut mix: ResMut<MusicMix>, settings: Res<Settings>) {
+    *mix = MusicMix::default();
+    let gains = mix.gains();
+    for (index, path) in MUSIC_LAYER_PATHS.iter().enumerate() {
+        commands.spawn((
+            AudioBundle {
+                source: asset_server.load(*path),
+                settings: PlaybackSettings::LOOP.with_volume(Volume::new(music_volume(&settings, gains[index]))),
+            },
+            MusicLayer { index },
+        ));
+    }
+}
+
+fn play_sound_effects(
+    mut commands: Commands,
+    sound_effects: Option<Res<SoundEffects>>,
+    settings: Res<Settings>,
+    mut food_eaten_events: EventReader<FoodEaten>,
+    mut poison_eaten_events: EventReader<PoisonEaten>,
+    mut tier_changed_events: EventReader<TierChanged>,
+) {
+    let Some(sound_effects) = sound_effects else { return; };
+    let mut sounds = vec![];
+    sounds.extend(food_eaten_events.read().filter(|event| event.kind == FoodKind::Normal).map(|_| sound_effects.eat.clone()));
+    sounds.extend(poison_eaten_events.read().map(|_| sound_effects.poison.clone()));
+    sounds.extend(tier_changed_events.read().map(|event| {
+        if tier_rank(event.to) > tier_rank(event.from) { sound_effects.tier_up.clone() } else { sound_effects.tier_down.clone() }
+    }));
+    for source in sounds {
+        commands.spawn(AudioBundle {
+            source,
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new(sfx_volume(&settings))),
+        });
+    }
+}
+
+fn music_mix_update(mut mix: ResMut<MusicMix>, mut tier_changed_events: EventReader<TierChanged>, time: Res<Time>) {
+    for event in tier_changed_events.read() {
+        mix.from = mix.gains();
+        mix.tier = event.to;
+        mix.elapsed = 0.0;
+    }
+    if mix.elapsed < SCALE_TRANSITION_DURATION {
+        mix.elapsed += time.delta_seconds();
+    }
+}
+
+// Also picks up volume changes made in the settings screen, paused or not.
+fn music_volume_update(mix: Res<MusicMix>, settings: Res<Settings>, sink_query: Query<(&MusicLayer, &AudioSink)>) {
+    let gains = mix.gains();
+    for (layer, sink) in &sink_query {
+        sink.set_volume(music_volume(&settings, gains[layer.index]));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossfade_starts_at_from_and_ends_at_to() {
+        let from = layer_gains(SnakeSpineNodeType::Small);
+        let to = layer_gains(SnakeSpineNodeType::Medium);
+        assert_eq!(crossfade_gains(from, to, 0.0), from);
+        assert_eq!(crossfade_gains(from, to, SCALE_TRANSITION_DURATION), to);
+        assert_eq!(crossfade_gains(from, to, SCALE_TRANSITION_DURATION * 3.0), to);
+    }
+
+    #[test]
+    fn crossfade_is_halfway_at_half_time() {
+        let gains = crossfade_gains([0.0, 1.0, 0.0], [1.0, 0.0, 0.0], SCALE_TRANSITION_DURATION / 2.0);
+        assert!((gains[0] - 0.5).abs() < 1e-5);
+        assert!((gains[1] - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn higher_tiers_add_layers() {
+        assert_eq!(layer_gains(SnakeSpineNodeType::Small)[1], 0.0);
+        assert!(layer_gains(SnakeSpineNodeType::Medium)[1] > 0.0);
+        assert!(layer_gains(SnakeSpineNodeType::Big)[2] > 0.0);
+    }
+
+    #[test]
+    fn volumes_scale_with_master() {
+        let settings = Settings { master_volume: 0.5, music_volume: 0.8, sfx_volume: 0.4, ..Settings::default() };
+        assert!((music_volume(&settings, 1.0) - 0.4).abs() < 1e-5);
+        assert!((sfx_volume(&settings) - 0.2).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,278 @@
+// Headless balancing simulator: plays the real gameplay plugins (snake, food, bound, tiers)
+// with a scripted bot instead of a player, for a range of seeds, and prints one CSV row of
+// stats per run. Used to see how the numbers in assets/game.tunables.ron and the tier
+// thresholds play out without sitting through the runs by hand.
+//
+//     cargo run --release --bin simulator -- --seeds 20 --minutes 5 --out stats.csv
+
+use std::f32::consts::{PI, TAU};
+use std::io::Write;
+use std::time::Duration;
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+
+use sylvester::creature_body_evolution::tier_rank;
+use sylvester::food::{Bound, Food};
+use sylvester::game_rng::FixedSeed;
+use sylvester::gameplay_events::{FoodEaten, FoodKind, PoisonEaten, TierChanged};
+use sylvester::input_map::{InputAction, InputMap};
+use sylvester::model::game_model::{AppState, PlayState};
+use sylvester::settings::Settings;
+use sylvester::snake_model::{SnakeModel, SnakeSpineNodeType};
+use sylvester::CreaturePlugins;
+
+const FRAMES_PER_SECOND: f64 = 60.0;
+// How far out (as a fraction of the bound radius) the bot heads back to the middle.
+const BOT_RETURN_FRACTION: f32 = 0.8;
+// Heading error (radians) the bot tolerates before turning.
+const BOT_AIM_TOLERANCE: f32 = 0.05;
+
+struct SimOptions {
+    first_seed: u64,
+    seeds: u64,
+    minutes: f64,
+    out: Option<String>,
+}
+
+impl Default for SimOptions {
+    fn default() -> Self {
+        Self { first_seed: 1, seeds: 10, minutes: 5.0, out: None }
+    }
+}
+
+fn parse_options(args: impl Iterator<Item = String>) -> Result<SimOptions, String> {
+    let mut options = SimOptions::default();
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+        match arg.as_str() {
+            "--first-seed" => options.first_seed = value()?.parse().map_err(|e| format!("--first-seed: {e}"))?,
+            "--seeds" => options.seeds = value()?.parse().map_err(|e| format!("--seeds: {e}"))?,
+            "--minutes" => options.minutes = value()?.parse().map_err(|e| format!("--minutes: {e}"))?,
+            "--out" => options.out = Some(value()?),
+            _ => return Err(format!("unknown argument {arg}")),
+        }
+    }
+    Ok(options)
+}
+
+// What one run produced.
+#[derive(Resource, Default)]
+struct SimStats {
+    // simulated seconds at which each tier was first reached
+    time_to_medium: Option<f32>,
+    time_to_big: Option<f32>,
+    food_eaten: u32,
+    poison_eaten: u32,
+    game_over: bool,
+}
+
+const CSV_HEADER: &str = "seed,seconds_played,time_to_medium,time_to_big,food_eaten,poison_eaten,final_size,bound_radius,game_over";
+
+fn csv_row(seed: u64, seconds_played: f32, stats: &SimStats, final_size: f32, bound_radius: f32) -> String {
+    let time = |t: Option<f32>| t.map(|t| format!("{t:.1}")).unwrap_or_default();
+    format!(
+        "{seed},{seconds_played:.1},{},{},{},{},{final_size:.1},{bound_radius:.1},{}",
+        time(stats.time_to_medium),
+        time(stats.time_to_big),
+        stats.food_eaten,
+        stats.poison_eaten,
+        stats.game_over,
+    )
+}
+
+// -1 = turn right, 1 = turn left, 0 = straight on. Chases `target`, unless the head has
+// wandered near the edge of the bound, in which case it heads back to the middle first.
+fn bot_turn(head_pos: Vec2, heading: f32, target: Option<Vec2>, bound_radius: f32) -> f32 {
+    let aim = if head_pos.length() > bound_radius * BOT_RETURN_FRACTION {
+        Vec2::ZERO
+    } else {
+        match target {
+            Some(target) => target,
+            None => return 0.0,
+        }
+    };
+    let to_aim = aim - head_pos;
+    let wanted = to_aim.y.atan2(to_aim.x);
+    let error = (wanted - heading + PI).rem_euclid(TAU) - PI;
+    if error > BOT_AIM_TOLERANCE { 1.0 } else if error < -BOT_AIM_TOLERANCE { -1.0 } else { 0.0 }
+}
+
+// Swims forward all the time towards the nearest normal food, by pressing the keys
+// snake_update reads through the InputMap.
+fn bot_input(
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    input_map: Res<InputMap>,
+    snake_query: Query<&SnakeModel>,
+    food_query: Query<&Food>,
+    bound_query: Query<&Bound>,
+) {
+    keyboard_input.reset_all();
+    let Some(snake) = snake_query.iter().next() else { return; };
+    let bound_radius = bound_query.iter().next().map(|bound| bound.radius).unwrap_or(f32::MAX);
+    let target = food_query
+        .iter()
+        .filter(|food| !food.is_poisonous())
+        .map(|food| food.pos())
+        .min_by(|a, b| a.distance_squared(snake.head_pos).total_cmp(&b.distance_squared(snake.head_pos)));
+
+    let mut actions = vec![InputAction::MoveForward];
+    let turn = bot_turn(snake.head_pos, snake.head_direction_angle, target, bound_radius);
+    if turn > 0.0 {
+        actions.push(InputAction::TurnLeft);
+    } else if turn < 0.0 {
+        actions.push(InputAction::TurnRight);
+    }
+    for action in actions {
+        for key in input_map.keys(action) {
+            keyboard_input.press(key);
+        }
+    }
+}
+
+fn record_stats(
+    mut stats: ResMut<SimStats>,
+    time: Res<Time>,
+    mut food_eaten_events: EventReader<FoodEaten>,
+    mut poison_eaten_events: EventReader<PoisonEaten>,
+    mut tier_changed_events: EventReader<TierChanged>,
+) {
+    stats.food_eaten += food_eaten_events.read().filter(|event| event.kind == FoodKind::Normal).count() as u32;
+    stats.poison_eaten += poison_eaten_events.read().count() as u32;
+    for event in tier_changed_events.read() {
+        if tier_rank(event.to) <= tier_rank(event.from) {
+            continue;
+        }
+        let reached = match event.to {
+            SnakeSpineNodeType::Medium => &mut stats.time_to_medium,
+            SnakeSpineNodeType::Big => &mut stats.time_to_big,
+            SnakeSpineNodeType::Small => continue,
+        };
+        reached.get_or_insert(time.elapsed_seconds());
+    }
+}
+
+fn record_game_over(mut stats: ResMut<SimStats>) {
+    stats.game_over = true;
+}
+
+fn sim_app(seed: u64) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AssetPlugin::default()));
+    // sprites and gizmos still get created headless; they just never reach a renderer
+    app.init_asset::<Image>();
+    app.init_asset::<Shader>();
+    app.add_plugins(bevy::gizmos::GizmoPlugin);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / FRAMES_PER_SECOND)));
+    app.init_resource::<ButtonInput<KeyCode>>();
+    app.insert_resource(Settings::default());
+    app.insert_resource(FixedSeed(seed));
+    app.init_resource::<SimStats>();
+
+    app.init_state::<AppState>();
+    app.add_sub_state::<PlayState>();
+    app.add_plugins((
+        CreaturePlugins,
+        sylvester::food::FoodPlugin,
+        sylvester::game_rng::GameRngPlugin,
+    ));
+    app.add_systems(PreUpdate, bot_input);
+    app.add_systems(PostUpdate, record_stats);
+    app.add_systems(OnEnter(AppState::GameOver), record_game_over);
+
+    app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::Playing);
+    app
+}
+
+fn simulate(seed: u64, minutes: f64) -> String {
+    let mut app = sim_app(seed);
+    let frames = (minutes * 60.0 * FRAMES_PER_SECOND) as u64;
+    for _ in 0..frames {
+        app.update();
+        if app.world().resource::<SimStats>().game_over {
+            break;
+        }
+    }
+
+    let world = app.world_mut();
+    let seconds_played = world.resource::<Time>().elapsed_seconds();
+    let final_size = world.query::<&SnakeModel>().iter(world).next().map(|snake| snake.size).unwrap_or(0.0);
+    let bound_radius = world.query::<&Bound>().iter(world).next().map(|bound| bound.radius).unwrap_or(0.0);
+    csv_row(seed, seconds_played, world.resource::<SimStats>(), final_size, bound_radius)
+}
+
+fn main() -> AppExit {
+    let options = match parse_options(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("usage: simulator [--first-seed N] [--seeds N] [--minutes M] [--out stats.csv]");
+            return AppExit::error();
+        }
+    };
+
+    let mut out: Box<dyn Write> = match &options.out {
+        Some(path) => match std::fs::File::create(path) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("can't write {path}: {e}");
+                return AppExit::error();
+            }
+        },
+        None => Box::new(std::io::stdout()),
+    };
+    let mut rows = vec![CSV_HEADER.to_string()];
+    for seed in options.first_seed..options.first_seed + options.seeds {
+        rows.push(simulate(seed, options.minutes));
+    }
+    if let Err(e) = writeln!(out, "{}", rows.join("\n")) {
+        eprintln!("can't write stats: {e}");
+        return AppExit::error();
+    }
+    AppExit::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bot_turns_towards_its_target() {
+        // heading right (angle 0), food up and to the right -> turn left (counter-clockwise)
+        assert_eq!(bot_turn(Vec2::ZERO, 0.0, Some(Vec2::new(100.0, 100.0)), 500.0), 1.0);
+        assert_eq!(bot_turn(Vec2::ZERO, 0.0, Some(Vec2::new(100.0, -100.0)), 500.0), -1.0);
+        assert_eq!(bot_turn(Vec2::ZERO, 0.0, Some(Vec2::new(100.0, 0.0)), 500.0), 0.0);
+    }
+
+    #[test]
+    fn bot_heads_home_near_the_edge() {
+        // at the right edge heading right, food further right -> still turns back
+        let turn = bot_turn(Vec2::new(450.0, 0.0), 0.0, Some(Vec2::new(490.0, 0.0)), 500.0);
+        assert_ne!(turn, 0.0);
+    }
+
+    #[test]
+    fn options_parse_and_reject_unknown_flags() {
+        let args = ["--seeds", "3", "--minutes", "0.5"].map(String::from);
+        let options = parse_options(args.into_iter()).unwrap();
+        assert_eq!(options.seeds, 3);
+        assert_eq!(options.minutes, 0.5);
+        assert!(parse_options(["--fast".to_string()].into_iter()).is_err());
+    }
+
+    #[test]
+    fn csv_rows_leave_unreached_tiers_empty() {
+        let stats = SimStats { time_to_medium: Some(12.34), food_eaten: 7, ..SimStats::default() };
+        assert_eq!(csv_row(3, 60.0, &stats, 12.0, 605.0), "3,60.0,12.3,,7,0,12.0,605.0,false");
+        assert_eq!(CSV_HEADER.split(',').count(), csv_row(3, 60.0, &stats, 12.0, 605.0).split(',').count());
+    }
+
+    #[test]
+    fn a_short_run_plays_headless() {
+        let row = simulate(7, 0.1);
+        assert!(row.starts_with("7,"));
+    }
+}
@@ -0,0 +1,167 @@
+use crate::snake_model::{SnakeModel, SnakeSpineNode};
+
+// Shape of the creature's body: how thick each segment is, as a function of its
+// normalized position along the body (0 = head, 1 = tail). Values are multipliers
+// of SnakeModel.node_radius, so the profile keeps its shape as the creature evolves.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BodyProfile {
+    // (normalized position, radius factor) control points, sorted by position.
+    // Linearly interpolated in between; flat past the first/last point.
+    pub points: Vec<(f32, f32)>,
+}
+
+impl Default for BodyProfile {
+    // Slim neck, thick middle and a tapering tail.
+    fn default() -> Self {
+        Self {
+            points: vec![
+                (0.0, 0.9),
+                (0.3, 1.2),
+                (0.6, 1.0),
+                (1.0, 0.4),
+            ],
+        }
+    }
+}
+
+impl BodyProfile {
+    pub fn radius_factor_at(&self, normalized_pos: f32) -> f32 {
+        let Some(&(first_pos, first_factor)) = self.points.first() else { return 1.0; };
+        if normalized_pos <= first_pos {
+            return first_factor;
+        }
+        for pair in self.points.windows(2) {
+            let (a_pos, a_factor) = pair[0];
+            let (b_pos, b_factor) = pair[1];
+            if normalized_pos <= b_pos {
+                let span = b_pos - a_pos;
+                if span <= 0.0 {
+                    return b_factor;
+                }
+                let t = (normalized_pos - a_pos) / span;
+                return a_factor + (b_factor - a_factor) * t;
+            }
+        }
+        self.points.last().map(|p| p.1).unwrap_or(1.0)
+    }
+}
+
+// Radius and distance back along the trace of every segment from 0 (head) to
+// `last_index` (tail). Spacing is accumulated from neighbouring radii
+// (distance_i = distance_(i-1) + radius_(i-1) + radius_i), so segments of
+// different sizes still just touch instead of overlapping or leaving gaps.
+pub fn body_layout(profile: &BodyProfile, node_radius: f32, last_index: usize) -> Vec<(f32, f32)> {
+    let mut layout: Vec<(f32, f32)> = Vec::with_capacity(last_index + 1);
+    let mut distance = 0.0;
+    let mut previous_radius = 0.0;
+    for i in 0..=last_index {
+        let normalized_pos = if last_index == 0 { 0.0 } else { i as f32 / last_index as f32 };
+        let radius = profile.radius_factor_at(normalized_pos) * node_radius;
+        if i != 0 {
+            distance += previous_radius + radius;
+        }
+        layout.push((distance, radius));
+        previous_radius = radius;
+    }
+    layout
+}
+
+// Writes the current layout into the visible part of snake.body (segments past
+// snake.size are parked by draw_nodes and keep whatever they had).
+pub fn update_body_layout(snake: &mut SnakeModel) {
+    let last_index = (snake.size as usize).min(snake.body.len().saturating_sub(1));
+    let layout = body_layout(&snake.body_profile, snake.node_radius, last_index);
+    for (node, (distance, radius)) in snake.body.iter_mut().zip(layout) {
+        set_node_layout(node, distance, radius);
+    }
+}
+
+// Gives a creature made without sprites (snake_model_new) a body up to its size, laid
+// out as update_body_layout does each frame, for tests that need segment positions.
+#[cfg(test)]
+pub fn lay_out_body_without_sprites(snake: &mut SnakeModel) {
+    snake.body = (0..=snake.size as usize)
+        .map(|_| SnakeSpineNode { distance_from_head: 0.0, radius: 0.0, node_type: bevy::prelude::Entity::PLACEHOLDER })
+        .collect();
+    update_body_layout(snake);
+}
+
+fn set_node_layout(node: &mut SnakeSpineNode, distance_from_head: f32, radius: f32) {
+    node.distance_from_head = distance_from_head;
+    node.radius = radius;
+}
+
+// How far back along the trace the tail's far edge reaches - the length the trace must cover.
+pub fn body_length(snake: &SnakeModel) -> f32 {
+    let last_index = snake.size as usize;
+    match snake.body.get(last_index) {
+        Some(tail) => tail.distance_from_head + tail.radius,
+        None => {
+            let layout = body_layout(&snake.body_profile, snake.node_radius, last_index);
+            layout.last().map(|(distance, radius)| distance + radius).unwrap_or(0.0)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_float_eq(a: f32, b: f32) {
+        let delta_max = 0.001;
+        let c = f32::abs(a - b);
+        assert!(c < delta_max)
+    }
+
+    #[test]
+    fn interpolates_between_control_points() {
+        let profile = BodyProfile { points: vec![(0.0, 1.0), (1.0, 2.0)] };
+        assert_float_eq(profile.radius_factor_at(0.5), 1.5);
+        assert_float_eq(profile.radius_factor_at(0.25), 1.25);
+    }
+
+    #[test]
+    fn flat_outside_control_points() {
+        let profile = BodyProfile { points: vec![(0.2, 1.0), (0.8, 2.0)] };
+        assert_eq!(profile.radius_factor_at(0.0), 1.0);
+        assert_eq!(profile.radius_factor_at(1.0), 2.0);
+    }
+
+    #[test]
+    fn default_profile_is_thick_in_the_middle_and_tapers_at_the_tail() {
+        let profile = BodyProfile::default();
+        let middle = profile.radius_factor_at(0.3);
+        assert!(middle > profile.radius_factor_at(0.0));
+        assert!(middle > profile.radius_factor_at(1.0));
+        assert!(profile.radius_factor_at(1.0) < profile.radius_factor_at(0.8));
+    }
+
+    #[test]
+    fn uniform_layout_matches_fixed_spacing() {
+        // before profiles, every segment sat at i * node_radius * 2
+        let uniform = BodyProfile { points: vec![(0.0, 1.0), (1.0, 1.0)] };
+        let layout = body_layout(&uniform, 10.0, 5);
+        for (i, (distance, radius)) in layout.iter().enumerate() {
+            assert_float_eq(*distance, i as f32 * 20.0);
+            assert_float_eq(*radius, 10.0);
+        }
+    }
+
+    #[test]
+    fn variable_radii_keep_segments_touching() {
+        let layout = body_layout(&BodyProfile::default(), 10.0, 8);
+        for pair in layout.windows(2) {
+            let (a_distance, a_radius) = pair[0];
+            let (b_distance, b_radius) = pair[1];
+            assert_float_eq(b_distance - a_distance, a_radius + b_radius);
+        }
+    }
+
+    #[test]
+    fn single_segment_layout_is_just_the_head() {
+        let layout = body_layout(&BodyProfile::default(), 10.0, 0);
+        assert_eq!(layout.len(), 1);
+        assert_float_eq(layout[0].0, 0.0);
+        assert_float_eq(layout[0].1, 9.0);
+    }
+}
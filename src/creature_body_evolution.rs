@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use bevy::{sprite::SpriteBundle};
 use crate::snake_model::{SnakeModel, SnakeSpineNode, SnakeSpineNodeType as SnakeSpineNodeType};
+use crate::gameplay_events::TierChanged;
+use crate::tunables::TierTunables;
 #[cfg(test)]
 use crate::snake_model::snake_model_new;
 
@@ -8,10 +10,6 @@ use crate::snake_model::snake_model_new;
 #[derive(Component)]
 pub struct CreatureBodyVisualElement;
 
-// Size (snake.size) at which the creature moves up to the next evolution tier.
-const MEDIUM_TIER_MIN_SIZE: f32 = 10.0;
-const BIG_TIER_MIN_SIZE: f32 = 25.0;
-
 // Base values matching today's Small tier, used to scale sprites/spacing proportionally
 // to node_radius as the creature evolves (see snake_extension.rs draw_nodes).
 pub const BASE_NODE_RADIUS: f32 = 10.0;
@@ -26,13 +24,55 @@ pub const BASE_END_SPRITE_SCALE: f32 = 0.12;
 // park segments that fall out of range when the creature shrinks (e.g. poison food).
 pub const PARKED_SEGMENT_POSITION: Vec3 = Vec3::new(1000.0, 0.0, 0.0);
 
-// Maps the snake's current size to its evolution tier.
-pub fn tier_for_size(size: f32) -> SnakeSpineNodeType {
-    if size >= BIG_TIER_MIN_SIZE { SnakeSpineNodeType::Big }
-    else if size >= MEDIUM_TIER_MIN_SIZE { SnakeSpineNodeType::Medium }
+// Maps the snake's current size to its evolution tier, at the thresholds in Tunables.tiers.
+pub fn tier_for_size(size: f32, tiers: &TierTunables) -> SnakeSpineNodeType {
+    if size >= tiers.big_min_size { SnakeSpineNodeType::Big }
+    else if size >= tiers.medium_min_size { SnakeSpineNodeType::Medium }
     else { SnakeSpineNodeType::Small }
 }
 
+// The tier after `tier` and the size needed to reach it, or None at the top tier.
+pub fn next_tier_threshold(tier: SnakeSpineNodeType, tiers: &TierTunables) -> Option<(SnakeSpineNodeType, f32)> {
+    match tier {
+        SnakeSpineNodeType::Small => Some((SnakeSpineNodeType::Medium, tiers.medium_min_size)),
+        SnakeSpineNodeType::Medium => Some((SnakeSpineNodeType::Big, tiers.big_min_size)),
+        SnakeSpineNodeType::Big => None,
+    }
+}
+
+// How far (0..1) `size` has come from its current tier's threshold toward the next one.
+// Always 1 at the top tier.
+pub fn tier_progress(size: f32, tiers: &TierTunables) -> f32 {
+    let tier = tier_for_size(size, tiers);
+    let tier_min_size = match tier {
+        SnakeSpineNodeType::Small => 0.0,
+        SnakeSpineNodeType::Medium => tiers.medium_min_size,
+        SnakeSpineNodeType::Big => tiers.big_min_size,
+    };
+    match next_tier_threshold(tier, tiers) {
+        Some((_, next_min_size)) => ((size - tier_min_size) / (next_min_size - tier_min_size)).clamp(0.0, 1.0),
+        None => 1.0,
+    }
+}
+
+// Orders tiers from smallest (0) to biggest, e.g. to keep track of the highest tier a run reached.
+pub fn tier_rank(tier: SnakeSpineNodeType) -> u8 {
+    match tier {
+        SnakeSpineNodeType::Small => 0,
+        SnakeSpineNodeType::Medium => 1,
+        SnakeSpineNodeType::Big => 2,
+    }
+}
+
+// Player-facing name of a tier.
+pub fn tier_name(tier: SnakeSpineNodeType) -> &'static str {
+    match tier {
+        SnakeSpineNodeType::Small => "Small",
+        SnakeSpineNodeType::Medium => "Medium",
+        SnakeSpineNodeType::Big => "Big",
+    }
+}
+
 // Seconds it takes node_radius to animate into a newly reached tier.
 pub const SCALE_TRANSITION_DURATION: f32 = 0.4;
 
@@ -45,9 +85,12 @@ pub fn ease_smoothstep(t: f32) -> f32 {
 // Advances the creature's size-tier evolution once per frame: detects a tier change
 // (kicking off a fresh transition from the current radius) and eases node_radius
 // toward the current tier's target radius over SCALE_TRANSITION_DURATION.
-pub fn update_evolution_transition(snake: &mut SnakeModel, delta_seconds: f32) {
-    let target_tier = tier_for_size(snake.size);
+// Returns the tier change on the frame one happens, for the caller to send.
+pub fn update_evolution_transition(snake: &mut SnakeModel, tiers: &TierTunables, delta_seconds: f32) -> Option<TierChanged> {
+    let target_tier = tier_for_size(snake.size, tiers);
+    let mut tier_changed = None;
     if target_tier != snake.evolution_tier {
+        tier_changed = Some(TierChanged { from: snake.evolution_tier, to: target_tier });
         snake.evolution_transition_start_radius = snake.node_radius;
         snake.evolution_tier = target_tier;
         snake.evolution_transition_elapsed = 0.0;
@@ -60,6 +103,7 @@ pub fn update_evolution_transition(snake: &mut SnakeModel, delta_seconds: f32) {
     } else {
         snake.node_radius = target_radius;
     }
+    tier_changed
 }
 
 // Pre-spawns one head sprite plus 100 body-segment sprites up front (rather than
@@ -105,6 +149,7 @@ pub fn spine_from_size(commands: &mut Commands,  asset_server: &Res<AssetServer>
     let mut list: Vec<SnakeSpineNode> = Vec::new();
     list.push(SnakeSpineNode {
         distance_from_head: 0.0,
+        radius: BASE_NODE_RADIUS,
         node_type: head_entity,
     });
 
@@ -127,6 +172,7 @@ fn spawn_body_node(commands: &mut Commands, asset_server: &Res<AssetServer>) ->
 
     SnakeSpineNode {
         distance_from_head: 50.0,
+        radius: BASE_NODE_RADIUS,
         node_type: node_entity,
     }
 }
@@ -153,22 +199,52 @@ pub fn node_radius(node: SnakeSpineNodeType) -> f32 {
 mod tests {
     use super::*;
 
+    const TIERS: TierTunables = TierTunables { medium_min_size: 10.0, big_min_size: 25.0 };
+
     #[test]
     fn small_tier_below_medium_threshold() {
-        assert_eq!(tier_for_size(0.0), SnakeSpineNodeType::Small);
-        assert_eq!(tier_for_size(9.9), SnakeSpineNodeType::Small);
+        assert_eq!(tier_for_size(0.0, &TIERS), SnakeSpineNodeType::Small);
+        assert_eq!(tier_for_size(9.9, &TIERS), SnakeSpineNodeType::Small);
     }
 
     #[test]
     fn medium_tier_at_and_above_threshold() {
-        assert_eq!(tier_for_size(10.0), SnakeSpineNodeType::Medium);
-        assert_eq!(tier_for_size(24.9), SnakeSpineNodeType::Medium);
+        assert_eq!(tier_for_size(10.0, &TIERS), SnakeSpineNodeType::Medium);
+        assert_eq!(tier_for_size(24.9, &TIERS), SnakeSpineNodeType::Medium);
     }
 
     #[test]
     fn big_tier_at_and_above_threshold() {
-        assert_eq!(tier_for_size(25.0), SnakeSpineNodeType::Big);
-        assert_eq!(tier_for_size(100.0), SnakeSpineNodeType::Big);
+        assert_eq!(tier_for_size(25.0, &TIERS), SnakeSpineNodeType::Big);
+        assert_eq!(tier_for_size(100.0, &TIERS), SnakeSpineNodeType::Big);
+    }
+
+    #[test]
+    fn thresholds_come_from_the_tunables() {
+        let tiers = TierTunables { medium_min_size: 4.0, big_min_size: 8.0 };
+        assert_eq!(tier_for_size(5.0, &tiers), SnakeSpineNodeType::Medium);
+        assert_eq!(tier_for_size(8.0, &tiers), SnakeSpineNodeType::Big);
+        assert_eq!(tier_progress(6.0, &tiers), 0.5);
+    }
+
+    #[test]
+    fn tier_progress_runs_from_threshold_to_threshold() {
+        assert_eq!(tier_progress(0.0, &TIERS), 0.0);
+        assert_eq!(tier_progress(TIERS.medium_min_size / 2.0, &TIERS), 0.5);
+        assert_eq!(tier_progress(TIERS.medium_min_size, &TIERS), 0.0);
+        assert_eq!(tier_progress((TIERS.medium_min_size + TIERS.big_min_size) / 2.0, &TIERS), 0.5);
+    }
+
+    #[test]
+    fn tier_progress_is_full_at_the_top_tier() {
+        assert_eq!(next_tier_threshold(SnakeSpineNodeType::Big, &TIERS), None);
+        assert_eq!(tier_progress(TIERS.big_min_size + 10.0, &TIERS), 1.0);
+    }
+
+    #[test]
+    fn tier_rank_grows_with_tier() {
+        assert!(tier_rank(SnakeSpineNodeType::Small) < tier_rank(SnakeSpineNodeType::Medium));
+        assert!(tier_rank(SnakeSpineNodeType::Medium) < tier_rank(SnakeSpineNodeType::Big));
     }
 
     #[test]
@@ -187,16 +263,26 @@ mod tests {
     #[test]
     fn no_tier_change_settles_immediately_at_current_target() {
         let mut snake = snake_model_new(0); // starts at size 5.0, Small tier, already settled
-        update_evolution_transition(&mut snake, 0.1);
+        assert!(update_evolution_transition(&mut snake, &TIERS, 0.1).is_none());
         assert_eq!(snake.node_radius, BASE_NODE_RADIUS);
     }
 
+    #[test]
+    fn crossing_a_threshold_reports_the_tier_change_once() {
+        let mut snake = snake_model_new(0);
+        snake.size = TIERS.medium_min_size;
+        let tier_changed = update_evolution_transition(&mut snake, &TIERS, 0.1).unwrap();
+        assert_eq!(tier_changed.from, SnakeSpineNodeType::Small);
+        assert_eq!(tier_changed.to, SnakeSpineNodeType::Medium);
+        assert!(update_evolution_transition(&mut snake, &TIERS, 0.1).is_none());
+    }
+
     #[test]
     fn tier_change_eases_toward_target_instead_of_jumping() {
         let mut snake = snake_model_new(0);
-        snake.size = MEDIUM_TIER_MIN_SIZE; // crosses into Medium tier
-        update_evolution_transition(&mut snake, 0.1); // starts the transition (t=0 this frame)
-        update_evolution_transition(&mut snake, 0.1); // elapsed has now advanced, t>0
+        snake.size = TIERS.medium_min_size; // crosses into Medium tier
+        update_evolution_transition(&mut snake, &TIERS, 0.1); // starts the transition (t=0 this frame)
+        update_evolution_transition(&mut snake, &TIERS, 0.1); // elapsed has now advanced, t>0
         assert_eq!(snake.evolution_tier, SnakeSpineNodeType::Medium);
         assert!(snake.node_radius > BASE_NODE_RADIUS);
         assert!(snake.node_radius < node_radius(SnakeSpineNodeType::Medium));
@@ -205,9 +291,9 @@ mod tests {
     #[test]
     fn transition_settles_at_target_once_duration_elapses() {
         let mut snake = snake_model_new(0);
-        snake.size = MEDIUM_TIER_MIN_SIZE;
-        update_evolution_transition(&mut snake, SCALE_TRANSITION_DURATION); // starts the transition; elapsed reaches the duration
-        update_evolution_transition(&mut snake, 0.0); // elapsed is no longer < duration -> snaps to target
+        snake.size = TIERS.medium_min_size;
+        update_evolution_transition(&mut snake, &TIERS, SCALE_TRANSITION_DURATION); // starts the transition; elapsed reaches the duration
+        update_evolution_transition(&mut snake, &TIERS, 0.0); // elapsed is no longer < duration -> snaps to target
         assert_eq!(snake.node_radius, node_radius(SnakeSpineNodeType::Medium));
     }
 }
\ No newline at end of file
@@ -0,0 +1,276 @@
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::prelude::*;
+
+use crate::creature_body_evolution::tier_name;
+use crate::game_clock::GameClock;
+use crate::food::{Bound, Food, target_food_count};
+use crate::gameplay_events::SizeChanged;
+use crate::grid::{DebugDraw, DebugDrawCategory};
+use crate::input_map::{InputAction, InputMap};
+use crate::snake_model::SnakeModel;
+use crate::tunables::Tunables;
+use crate::ui::button::spawn_button;
+use crate::ui::theme::{TEXT_COLOR, label, row, text_style};
+use crate::ui::toggle::{Toggle, spawn_toggle};
+
+pub struct DebugInspectorPlugin;
+
+impl Plugin for DebugInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        app.add_systems(Startup, spawn_inspector_panel);
+        app.add_systems(Update, (
+            toggle_inspector,
+            inspector_step_pressed,
+            inspector_text_update.run_if(inspector_visible),
+            debug_draw_toggle_changed,
+            debug_draw_toggle_sync.run_if(resource_changed::<DebugDraw>),
+        ).chain());
+    }
+}
+
+// Live view of the creature and the world around it, toggled with "2"
+// (InputAction::ToggleInspector) next to the gizmo grid on "1". Size and speeds can be
+// nudged with the -/+ buttons while playing, or while paused to set up a situation, and
+// each DebugDraw category has an on/off toggle mirroring keys 3-9.
+
+const PANEL_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.75);
+const INSPECTOR_FONT_SIZE: f32 = 16.0;
+// above the pause overlay and settings screen
+const INSPECTOR_Z_INDEX: i32 = 10;
+
+#[derive(Component)]
+struct InspectorPanel;
+
+#[derive(Component)]
+struct InspectorText;
+
+#[derive(Component)]
+struct DebugDrawToggle(DebugDrawCategory);
+
+// The SnakeModel fields the panel can edit.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum InspectorField {
+    Size,
+    MovementSpeed,
+    RotationSpeed,
+}
+
+// A -/+ button: pressing it adds `delta` to `field`.
+#[derive(Component)]
+struct InspectorStep {
+    field: InspectorField,
+    delta: f32,
+}
+
+const EDITABLE_FIELDS: [(InspectorField, &str, f32); 3] = [
+    (InspectorField::Size, "size", 1.0),
+    (InspectorField::MovementSpeed, "speed", 25.0),
+    (InspectorField::RotationSpeed, "turn", 0.5),
+];
+
+// Applies one step to the snake, keeping the value somewhere the game can still run with.
+pub fn step_field(snake: &mut SnakeModel, field: InspectorField, delta: f32) {
+    match field {
+        InspectorField::Size => snake.size = (snake.size + delta).max(1.0),
+        InspectorField::MovementSpeed => snake.movement_speed = (snake.movement_speed + delta).max(0.0),
+        InspectorField::RotationSpeed => snake.rotation_speed_in_degrees = (snake.rotation_speed_in_degrees + delta).max(0.0),
+    }
+}
+
+// Everything outside SnakeModel the panel shows.
+pub struct WorldStats {
+    pub food_count: usize,
+    pub bound_radius: Option<f32>,
+    pub fps: Option<f64>,
+    pub time_scale: f32,
+    pub clock_paused: bool,
+}
+
+pub fn inspector_text(snake: Option<&SnakeModel>, world: &WorldStats, tunables: &Tunables) -> String {
+    let mut lines = vec![];
+    match snake {
+        Some(snake) => {
+            lines.push(format!("head_pos: ({:.1}, {:.1})", snake.head_pos.x, snake.head_pos.y));
+            lines.push(format!("angle: {:.1} deg", snake.head_direction_angle.to_degrees()));
+            lines.push(format!("size: {:.1}", snake.size));
+            lines.push(format!("speed: {:.1}  turn {:.1}", snake.movement_speed, snake.rotation_speed_in_degrees));
+            lines.push(format!("node_radius: {:.2}", snake.node_radius));
+            lines.push(format!("tier: {}", tier_name(snake.evolution_tier)));
+            lines.push(format!("transition: {:.2}s", snake.evolution_transition_elapsed));
+            lines.push(format!("trace: {} (pruned {})", snake.trace.len(), snake.trace_pruned_count));
+            lines.push(format!("food: {} / {}", world.food_count, target_food_count(snake.size, tunables)));
+        }
+        None => lines.push("no creature".to_string()),
+    }
+    match world.bound_radius {
+        Some(radius) => lines.push(format!("bound: {radius:.0}")),
+        None => lines.push("bound: -".to_string()),
+    }
+    match world.fps {
+        Some(fps) => lines.push(format!("fps: {fps:.0}")),
+        None => lines.push("fps: -".to_string()),
+    }
+    lines.push(format!("clock: {:.2}x{}", world.time_scale, if world.clock_paused { " (paused, \".\" steps)" } else { "" }));
+    lines.join("\n")
+}
+
+fn spawn_inspector_panel(mut commands: Commands, asset_server: Res<AssetServer>, debug_draw: Res<DebugDraw>) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(10.0),
+                right: Val::Px(10.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(6.0),
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: PANEL_COLOR.into(),
+            border_radius: BorderRadius::all(Val::Px(6.0)),
+            visibility: Visibility::Hidden,
+            z_index: ZIndex::Global(INSPECTOR_Z_INDEX),
+            ..default()
+        },
+        InspectorPanel,
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section("", text_style(&asset_server, INSPECTOR_FONT_SIZE, TEXT_COLOR)),
+            InspectorText,
+        ));
+        for (field, name, step) in EDITABLE_FIELDS {
+            parent.spawn(row()).with_children(|parent| {
+                parent.spawn(label(&asset_server, name, INSPECTOR_FONT_SIZE));
+                spawn_button(parent, &asset_server, "-", INSPECTOR_FONT_SIZE).insert(InspectorStep { field, delta: -step });
+                spawn_button(parent, &asset_server, "+", INSPECTOR_FONT_SIZE).insert(InspectorStep { field, delta: step });
+            });
+        }
+        for category in DebugDrawCategory::ALL {
+            parent.spawn(row()).with_children(|parent| {
+                parent.spawn(label(&asset_server, category.name(), INSPECTOR_FONT_SIZE));
+                spawn_toggle(parent, &asset_server, debug_draw.shows(category)).insert(DebugDrawToggle(category));
+            });
+        }
+    });
+}
+
+fn toggle_inspector(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_map: Res<InputMap>,
+    mut panel_query: Query<&mut Visibility, With<InspectorPanel>>,
+) {
+    if !input_map.just_pressed(InputAction::ToggleInspector, &keyboard_input) {
+        return;
+    }
+    for mut visibility in &mut panel_query {
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Visible,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+// Size edits go out as SizeChanged like eating does, so the food pool follows along.
+fn inspector_step_pressed(
+    step_query: Query<(&Interaction, &InspectorStep), Changed<Interaction>>,
+    mut snake_query: Query<(Entity, &mut SnakeModel)>,
+    mut size_changed_events: EventWriter<SizeChanged>,
+) {
+    for (interaction, step) in &step_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for (creature, mut snake) in &mut snake_query {
+            let from = snake.size;
+            step_field(&mut snake, step.field, step.delta);
+            if snake.size != from {
+                size_changed_events.send(SizeChanged { creature, from, to: snake.size });
+            }
+        }
+    }
+}
+
+fn inspector_visible(panel_query: Query<&Visibility, With<InspectorPanel>>) -> bool {
+    panel_query.iter().any(|visibility| *visibility != Visibility::Hidden)
+}
+
+fn inspector_text_update(
+    mut text_query: Query<&mut Text, With<InspectorText>>,
+    snake_query: Query<&SnakeModel>,
+    food_query: Query<(), With<Food>>,
+    bound_query: Query<&Bound>,
+    diagnostics: Res<DiagnosticsStore>,
+    tunables: Res<Tunables>,
+    clock: Res<GameClock>,
+) {
+    let world = WorldStats {
+        food_count: food_query.iter().count(),
+        bound_radius: bound_query.iter().next().map(|bound| bound.radius),
+        fps: diagnostics.get(&FrameTimeDiagnosticsPlugin::FPS).and_then(|fps| fps.smoothed()),
+        time_scale: clock.scale(),
+        clock_paused: clock.is_paused(),
+    };
+    let shown = inspector_text(snake_query.iter().next(), &world, &tunables);
+    for mut text in &mut text_query {
+        text.sections[0].value.clone_from(&shown);
+    }
+}
+
+fn debug_draw_toggle_changed(
+    toggle_query: Query<(&Toggle, &DebugDrawToggle), Changed<Toggle>>,
+    mut debug_draw: ResMut<DebugDraw>,
+) {
+    for (toggle, DebugDrawToggle(category)) in &toggle_query {
+        if debug_draw.shows(*category) != toggle.on {
+            debug_draw.set_shown(*category, toggle.on);
+        }
+    }
+}
+
+// Keeps the toggles in step when a category is flipped from the keyboard.
+fn debug_draw_toggle_sync(debug_draw: Res<DebugDraw>, mut toggle_query: Query<(&mut Toggle, &DebugDrawToggle)>) {
+    for (mut toggle, DebugDrawToggle(category)) in &mut toggle_query {
+        toggle.set_if_neq(Toggle { on: debug_draw.shows(*category) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snake_model::snake_model_new;
+
+    #[test]
+    fn steps_are_clamped_to_playable_values() {
+        let mut snake = snake_model_new(0);
+        step_field(&mut snake, InspectorField::Size, -100.0);
+        assert_eq!(snake.size, 1.0);
+        step_field(&mut snake, InspectorField::MovementSpeed, 25.0);
+        assert_eq!(snake.movement_speed, 175.0);
+        step_field(&mut snake, InspectorField::RotationSpeed, -10.0);
+        assert_eq!(snake.rotation_speed_in_degrees, 0.0);
+    }
+
+    #[test]
+    fn text_lists_snake_and_world_stats() {
+        let snake = snake_model_new(0);
+        let world = WorldStats { food_count: 4, bound_radius: Some(500.0), fps: None, time_scale: 0.25, clock_paused: true };
+        let text = inspector_text(Some(&snake), &world, &Tunables::default());
+        assert!(text.contains("size: 5.0"));
+        assert!(text.contains("tier: Small"));
+        assert!(text.contains("food: 4 / 5"));
+        assert!(text.contains("bound: 500"));
+        assert!(text.contains("fps: -"));
+        assert!(text.contains("clock: 0.25x (paused"));
+    }
+
+    #[test]
+    fn text_without_a_creature_still_shows_the_world() {
+        let world = WorldStats { food_count: 0, bound_radius: None, fps: Some(60.0), time_scale: 1.0, clock_paused: false };
+        let text = inspector_text(None, &world, &Tunables::default());
+        assert!(text.starts_with("no creature"));
+        assert!(text.contains("fps: 60"));
+    }
+}
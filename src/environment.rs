@@ -1,16 +1,18 @@
 use bevy::prelude::*;
 use bevy::color::Mix;
 
-use crate::model::game_model::AppState;
+use crate::model::game_model::{AppState, PlayState};
 use crate::snake_model::{SnakeModel, SnakeSpineNodeType};
 use crate::creature_body_evolution::{SCALE_TRANSITION_DURATION, ease_smoothstep};
+use crate::game_clock::GameClock;
 
 pub struct EnvironmentPlugin;
 
 impl Plugin for EnvironmentPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<BackgroundTransition>();
-        app.add_systems(Update, background_color_update.run_if(in_state(AppState::Playing)));
+        app.add_systems(Update, background_color_update.run_if(in_state(PlayState::Running)));
+        app.add_systems(OnExit(AppState::Playing), reset_background);
     }
 }
 
@@ -43,11 +45,17 @@ impl Default for BackgroundTransition {
     }
 }
 
+// Back to the Small tier's color between runs, so menus and the next run start from it.
+fn reset_background(mut transition: ResMut<BackgroundTransition>, mut clear_color: ResMut<ClearColor>) {
+    *transition = BackgroundTransition::default();
+    clear_color.0 = transition.start_color;
+}
+
 fn background_color_update(
     snake_query: Query<&SnakeModel>,
     mut transition: ResMut<BackgroundTransition>,
     mut clear_color: ResMut<ClearColor>,
-    time: Res<Time>,
+    clock: Res<GameClock>,
 ) {
     let Some(snake) = snake_query.iter().next() else { return; };
 
@@ -61,7 +69,7 @@ fn background_color_update(
     if transition.elapsed < SCALE_TRANSITION_DURATION {
         let t = ease_smoothstep(transition.elapsed / SCALE_TRANSITION_DURATION);
         clear_color.0 = transition.start_color.mix(&target_color, t);
-        transition.elapsed += time.delta_seconds();
+        transition.elapsed += clock.delta_seconds();
     } else {
         clear_color.0 = target_color;
     }
@@ -1,5 +1,6 @@
 use bevy::color::Color;
 use bevy::math::Vec2;
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use rand::Rng;
 use std::f32::*;
@@ -7,107 +8,162 @@ use std::f64::consts::PI;
 
 use crate::snake_model::SnakeModel;
 use crate::grid::*;
-use super::STARTING_SNAKE_SIZE;
-use super::bound::{Bound, BASE_BOUND_RADIUS};
-use super::score::{Score, add_point};
+use crate::game_rng::GameRng;
+use crate::model::game_model::AppState;
+use crate::settings::Settings;
+use crate::gameplay_events::{CreatureSevered, FoodEaten, FoodKind, PoisonEaten, SizeChanged};
+use crate::tunables::{FoodTunables, Tunables};
+use crate::game_clock::GameClock;
+use super::bound::Bound;
 
 #[derive(Component)]
-pub(super) struct Food {
+pub struct Food {
     pos: Vec2,
     direction: f32,
     radius: f32,
     is_poisonous: bool,
+    // left behind by a severed body (ouroboros self-collision): gone once eaten
+    // instead of coming back somewhere else
+    one_shot: bool,
+    // seconds of game time before it can be eaten
+    pickup_delay: f32,
+}
+
+impl Food {
+    pub fn pos(&self) -> Vec2 {
+        self.pos
+    }
+
+    pub fn is_poisonous(&self) -> bool {
+        self.is_poisonous
+    }
 }
 
-// Chance any given food spawn/respawn is poisonous.
-const POISON_CHANCE: f64 = 0.2;
-// Size lost when eating poisonous food - a real setback, not devastating.
-const POISON_SIZE_PENALTY: f32 = 3.0;
-// Floor so the creature never shrinks to nothing.
-const MIN_SNAKE_SIZE: f32 = 1.0;
 // Warning tint applied to poisonous food's sprite so it's learnable/avoidable.
 // Bright and saturated (not dark) so it reads clearly against the dark background instead of blending in.
 const POISON_SPRITE_COLOR: Color = Color::srgb(0.2, 1.0, 0.3);
+// Poison tint with Settings.colorblind_palette on: orange instead of green, which stays
+// distinct from white food and the blue/teal backgrounds under red-green color blindness.
+const COLORBLIND_POISON_SPRITE_COLOR: Color = Color::srgb(1.0, 0.55, 0.0);
+// Severed pieces are dropped right under the head, well inside its eating reach - without
+// a pause it would swallow the bitten segment and its neighbours again on the next frame.
+const SEVERED_FOOD_PICKUP_DELAY: f32 = 1.0;
+
+// Growth is measured from the creature's starting size, so the count only rises once it grows beyond it.
+pub fn target_food_count(snake_size: f32, tunables: &Tunables) -> usize {
+    let food = &tunables.food;
+    let extra = ((snake_size - tunables.creature.starting_size).max(0.0) / food.size_per_extra_food) as usize;
+    (food.base_food_count + extra).min(food.max_food_count)
+}
+
+// What every system that puts food into the world needs, bundled so they don't each list it.
+#[derive(SystemParam)]
+pub(super) struct FoodSpawner<'w, 's> {
+    commands: Commands<'w, 's>,
+    asset_server: Res<'w, AssetServer>,
+    game_rng: ResMut<'w, GameRng>,
+    settings: Res<'w, Settings>,
+    tunables: Res<'w, Tunables>,
+}
+
+impl FoodSpawner<'_, '_> {
+    // Somewhere inside a bound of `bound_radius`, poisonous by chance.
+    fn spawn(&mut self, bound_radius: f32) {
+        let pos = new_food_position(&mut self.game_rng.rng, bound_radius, &self.tunables.food);
+        let is_poisonous = new_food_is_poisonous(&mut self.game_rng.rng, &self.tunables.food);
+        self.spawn_at(pos, is_poisonous, false);
+    }
+
+    fn spawn_at(&mut self, pos: Vec2, is_poisonous: bool, one_shot: bool) {
+        let food_image_size = 100.0;
+        let radius = 10.0;
+        let scale = (radius * 2.0) / food_image_size;
+        let rng = &mut self.game_rng.rng;
+        let initial_direction = rng.gen_range(0.0..= consts::PI * 2.0);
+        let direction = new_food_direction(rng, initial_direction);
+        self.commands.spawn((
+            SpriteBundle {
+                texture: self.asset_server.load("Food.png"),
+                transform: Transform::from_xyz(pos.x, pos.y, 0.0).with_scale(Vec3::new(scale, scale, scale)),
+                sprite: Sprite { color: food_sprite_color(is_poisonous, self.settings.colorblind_palette), ..default() },
+                ..default()
+            },
+            Food {
+                pos,
+                direction,
+                radius,
+                is_poisonous,
+                one_shot,
+                pickup_delay: if one_shot { SEVERED_FOOD_PICKUP_DELAY } else { 0.0 },
+            }
+        ));
+    }
+}
 
-const BASE_FOOD_COUNT: usize = 5; // today's fixed count
-const SIZE_PER_EXTRA_FOOD: f32 = 5.0;
-const MAX_FOOD_COUNT: usize = 20; // cap so entity count doesn't grow unbounded over a long session
-// Fraction of the boundary radius food spawns within, keeping it off the very edge.
-const FOOD_SPAWN_MARGIN_FACTOR: f32 = 0.6;
-
-pub(super) fn target_food_count(snake_size: f32) -> usize {
-    let extra = ((snake_size - STARTING_SNAKE_SIZE).max(0.0) / SIZE_PER_EXTRA_FOOD) as usize;
-    (BASE_FOOD_COUNT + extra).min(MAX_FOOD_COUNT)
-}
-
-pub(super) fn food_start(mut commands: Commands, asset_server: Res<AssetServer>) {
-    for _ in 0..BASE_FOOD_COUNT {
-        spawn_food(&mut commands, &asset_server, BASE_BOUND_RADIUS);
-    }
-}
-
-fn spawn_food(commands: &mut Commands, asset_server: &Res<AssetServer>, bound_radius: f32) {
-    let food_image_size = 100.0;
-    let radius = 10.0;
-    let scale = (radius * 2.0) / food_image_size;
-    let pos = new_food_position(bound_radius);
-    let is_poisonous = new_food_is_poisonous();
-    commands.spawn((
-        SpriteBundle {
-            texture: asset_server.load("Food.png"),
-            transform: Transform::from_xyz(pos.x, pos.y, 0.0).with_scale(Vec3::new(scale, scale, scale)),
-            sprite: Sprite { color: food_sprite_color(is_poisonous), ..default() },
-            ..default()
-        },
-        Food {
-            pos,
-            direction: new_food_direction(rand::thread_rng().gen_range(0.0..= consts::PI * 2.0) as f32),
-            radius,
-            is_poisonous,
+pub(super) fn food_start(mut spawner: FoodSpawner) {
+    let bound_radius = spawner.tunables.bound.base_radius;
+    for _ in 0..spawner.tunables.food.base_food_count {
+        spawner.spawn(bound_radius);
+    }
+}
+
+// What's left of a severed body becomes normal food, one piece per lost segment.
+pub(super) fn spawn_severed_food(mut spawner: FoodSpawner, mut severed_events: EventReader<CreatureSevered>) {
+    for event in severed_events.read() {
+        for &pos in &event.positions {
+            spawner.spawn_at(pos, false, true);
         }
-    ));
+    }
 }
 
 // Tops up the food pool toward target_food_count as the snake grows, mirroring
 // ensure_body_capacity's pattern in creature_body_evolution.rs.
 pub(super) fn ensure_food_capacity(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    snake_query: Query<&SnakeModel>,
+    mut spawner: FoodSpawner,
+    mut size_changed_events: EventReader<SizeChanged>,
     existing_food_query: Query<&Food>,
     bound_query: Query<&Bound>,
 ) {
-    let Some(snake) = snake_query.iter().next() else { return; };
-    let target = target_food_count(snake.size);
+    // shrinking never removes food, so only growth matters here
+    let Some(size) = size_changed_events.read().filter(|event| event.to > event.from).last().map(|event| event.to) else { return; };
+    let target = target_food_count(size, &spawner.tunables);
     let current = existing_food_query.iter().count();
     if current < target {
-        let bound_radius = bound_query.iter().next().map(|b| b.radius).unwrap_or(BASE_BOUND_RADIUS);
+        let bound_radius = bound_query.iter().next().map(|b| b.radius).unwrap_or(spawner.tunables.bound.base_radius);
         for _ in current..target {
-            spawn_food(&mut commands, &asset_server, bound_radius);
+            spawner.spawn(bound_radius);
         }
     }
 }
 
-fn new_food_position(bound_radius: f32) -> Vec2 {
-    let spawn_radius = bound_radius * FOOD_SPAWN_MARGIN_FACTOR;
-    let x = rand::thread_rng().gen_range(-spawn_radius..=spawn_radius);
-    let y = rand::thread_rng().gen_range(-spawn_radius..=spawn_radius);
+fn new_food_position(rng: &mut impl Rng, bound_radius: f32, food_tunables: &FoodTunables) -> Vec2 {
+    let spawn_radius = bound_radius * food_tunables.spawn_margin_factor;
+    let x = rng.gen_range(-spawn_radius..=spawn_radius);
+    let y = rng.gen_range(-spawn_radius..=spawn_radius);
     Vec2::new(x, y)
 }
 // Picks a new wander direction roughly opposite the last one (+/- a small random
 // wobble), used both when food is eaten/respawned and when it bounces off the bound.
-fn new_food_direction(last_direction: f32) -> f32 {
-    let num = rand::thread_rng().gen_range(-10.0..= 10.0) as f32;
+fn new_food_direction(rng: &mut impl Rng, last_direction: f32) -> f32 {
+    let num = rng.gen_range(-10.0..= 10.0) as f32;
     let new_direction = last_direction - consts::PI + (num / 10.0);
     new_direction
 }
-fn new_food_is_poisonous() -> bool {
-    rand::thread_rng().gen_bool(POISON_CHANCE)
+fn new_food_is_poisonous(rng: &mut impl Rng, food_tunables: &FoodTunables) -> bool {
+    rng.gen_bool(food_tunables.poison_chance)
 }
 // Maps poison state to the food's actual on-screen sprite tint (the warning color
 // for poisonous food, or white/untouched for normal food's natural Food.png look).
-fn food_sprite_color(is_poisonous: bool) -> Color {
-    if is_poisonous { POISON_SPRITE_COLOR } else { Color::WHITE }
+pub fn food_sprite_color(is_poisonous: bool, colorblind_palette: bool) -> Color {
+    match (is_poisonous, colorblind_palette) {
+        (true, false) => POISON_SPRITE_COLOR,
+        (true, true) => COLORBLIND_POISON_SPRITE_COLOR,
+        (false, _) => Color::WHITE,
+    }
+}
+
+fn poison_ends_run(snake_size: f32, food_tunables: &FoodTunables) -> bool {
+    snake_size <= food_tunables.min_snake_size
 }
 
 // True when the snake's head circle overlaps the food's circle.
@@ -120,7 +176,7 @@ fn snake_eats_food(
     distance_between < food.radius + snake.head_radius
 }
 // Redirects food that has wandered too close to the boundary edge, keeping it inside the play area.
-fn food_on_bound(food: &mut Food, bound_query: &Query<&mut Bound>) {
+fn food_on_bound(food: &mut Food, bound_query: &Query<&mut Bound>, rng: &mut impl Rng) {
     for bound in bound_query {
         let origin = Vec2::new(0.0, 0.0);
         let distance_from_origin_to_food: f32 = {
@@ -128,91 +184,178 @@ fn food_on_bound(food: &mut Food, bound_query: &Query<&mut Bound>) {
             ((distance_vector.x * distance_vector.x) + (distance_vector.y * distance_vector.y)).sqrt()
         };
         if distance_from_origin_to_food > (bound.radius - (food.radius * 2.0 )) {
-            food.direction = new_food_direction(food.direction)
+            food.direction = new_food_direction(rng, food.direction)
         }
     }
 }
 
-fn draw_food(food: &mut Food, gizmos: &mut Gizmos, query: &Query<&GridVisualDiagnostic>) {
+fn move_food(food: &mut Food, speed: f32, delta_seconds: f32) {
     let food_move = {
         let x = f32::cos(food.direction);
         let y = f32::sin(food.direction);
         Vec2::new(x, y)
     };
-    food.pos += food_move;
+    food.pos += food_move * speed * delta_seconds;
+}
 
-    if grid_draw_visual_diagnostics_info(&query) {
-        gizmos.circle_2d(food.pos, food.radius, food_sprite_color(food.is_poisonous));
+// Detects the snake's head touching food and reports it; what eating does is up to the
+// systems reading FoodEaten/PoisonEaten (growth below, score.rs, audio.rs, ...).
+pub(super) fn food_collision(
+    mut food_query: Query<(Entity, &mut Food)>,
+    snake_query: Query<(Entity, &SnakeModel)>,
+    mut food_eaten_events: EventWriter<FoodEaten>,
+    mut poison_eaten_events: EventWriter<PoisonEaten>,
+    clock: Res<GameClock>,
+) {
+    for (food_entity, mut food) in &mut food_query {
+        if food.pickup_delay > 0.0 {
+            food.pickup_delay -= clock.delta_seconds();
+            continue;
+        }
+        for (creature, snake) in &snake_query {
+            if snake_eats_food(snake, &food) {
+                food_eaten_events.send(FoodEaten { creature, food: food_entity, kind: FoodKind::of(food.is_poisonous), pos: food.pos });
+                if food.is_poisonous {
+                    poison_eaten_events.send(PoisonEaten { creature, pos: food.pos });
+                }
+                break;
+            }
+        }
     }
 }
 
-pub(super) fn food_update(
-    mut gizmos: Gizmos,
-    bound_query: Query<&mut Bound>,
-    mut food_query: Query<(&mut Food, &mut Transform, &mut Sprite)>,
+// Normal food grows the creature, poisonous food shrinks it. Poison eaten while already
+// at MIN_SNAKE_SIZE has nothing left to take, and ends the run.
+pub(super) fn apply_food_growth(
+    mut food_eaten_events: EventReader<FoodEaten>,
     mut snake_query: Query<&mut SnakeModel>,
-    mut score_query: Query<(&mut Text, &mut Score)>,
-    query: Query<&GridVisualDiagnostic>,
-
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut size_changed_events: EventWriter<SizeChanged>,
+    tunables: Res<Tunables>,
 ) {
-    let bound_radius = bound_query.iter().next().map(|b| b.radius).unwrap_or(BASE_BOUND_RADIUS);
-
-    for (mut food, mut transform, mut sprite) in &mut food_query {
-        for mut snake in &mut snake_query {
-            // eating: poisonous food shrinks the snake, normal food grows it and bumps score;
-            // either way the food respawns elsewhere with a freshly rolled poison state
-            if snake_eats_food(&snake, &food) {
-                if food.is_poisonous {
-                    snake.size = (snake.size - POISON_SIZE_PENALTY).max(MIN_SNAKE_SIZE);
-                } else {
-                    snake.size += 1.0;
-                    add_point(&mut score_query);
+    let food_tunables = &tunables.food;
+    for event in food_eaten_events.read() {
+        let Ok(mut snake) = snake_query.get_mut(event.creature) else { continue; };
+        let from = snake.size;
+        match event.kind {
+            FoodKind::Normal => snake.size += 1.0,
+            FoodKind::Poison => {
+                if poison_ends_run(snake.size, food_tunables) {
+                    next_app_state.set(AppState::GameOver);
                 }
-
-                food.direction = new_food_direction(food.direction);
-                food.pos = new_food_position(bound_radius);
-                food.is_poisonous = new_food_is_poisonous();
-                sprite.color = food_sprite_color(food.is_poisonous);
-                break;
+                snake.size = (snake.size - food_tunables.poison_size_penalty).max(food_tunables.min_snake_size);
             }
         }
+        if snake.size != from {
+            size_changed_events.send(SizeChanged { creature: event.creature, from, to: snake.size });
+        }
+    }
+}
 
-        draw_food(&mut food, &mut gizmos, &query);
+// Eaten food comes back somewhere else in the bound, with a freshly rolled poison state.
+// One-shot food is just removed.
+pub(super) fn respawn_eaten_food(
+    mut commands: Commands,
+    mut food_eaten_events: EventReader<FoodEaten>,
+    mut food_query: Query<(&mut Food, &mut Sprite)>,
+    bound_query: Query<&Bound>,
+    mut game_rng: ResMut<GameRng>,
+    settings: Res<Settings>,
+    tunables: Res<Tunables>,
+) {
+    let rng = &mut game_rng.rng;
+    let bound_radius = bound_query.iter().next().map(|b| b.radius).unwrap_or(tunables.bound.base_radius);
+    for event in food_eaten_events.read() {
+        let Ok((mut food, mut sprite)) = food_query.get_mut(event.food) else { continue; };
+        if food.one_shot {
+            commands.entity(event.food).despawn();
+            continue;
+        }
+        food.direction = new_food_direction(rng, food.direction);
+        food.pos = new_food_position(rng, bound_radius, &tunables.food);
+        food.is_poisonous = new_food_is_poisonous(rng, &tunables.food);
+        sprite.color = food_sprite_color(food.is_poisonous, settings.colorblind_palette);
+    }
+}
 
-        food_on_bound(&mut food, &bound_query);
+pub(super) fn food_movement(
+    bound_query: Query<&mut Bound>,
+    mut food_query: Query<(&mut Food, &mut Transform)>,
+    mut game_rng: ResMut<GameRng>,
+    tunables: Res<Tunables>,
+    clock: Res<GameClock>,
+) {
+    let rng = &mut game_rng.rng;
+    for (mut food, mut transform) in &mut food_query {
+        move_food(&mut food, tunables.food.wander_speed, clock.delta_seconds());
+
+        food_on_bound(&mut food, &bound_query, rng);
 
         transform.translation = Vec3::new(food.pos.x, food.pos.y, 0.0);
         transform.rotation = Quat::from_rotation_z(food.direction + consts::PI / 2.0 + consts::PI);
     }
 }
 
+// Debug gizmo outline of each food item's eating circle, tinted like its sprite.
+pub(super) fn draw_food_hitboxes(mut gizmos: Gizmos, food_query: Query<&Food>, debug_draw: Res<DebugDraw>, settings: Res<Settings>) {
+    if !debug_draw.draws(DebugDrawCategory::FoodHitboxes) {
+        return;
+    }
+    for food in &food_query {
+        gizmos.circle_2d(food.pos, food.radius, food_sprite_color(food.is_poisonous, settings.colorblind_palette));
+    }
+}
+
+// Retints food already on screen when the palette setting changes (also while paused).
+pub(super) fn food_palette_update(settings: Res<Settings>, mut food_query: Query<(&Food, &mut Sprite)>) {
+    for (food, mut sprite) in &mut food_query {
+        sprite.color = food_sprite_color(food.is_poisonous, settings.colorblind_palette);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn poisonous_food_gets_warning_tint() {
-        assert_eq!(food_sprite_color(true), POISON_SPRITE_COLOR);
+        assert_eq!(food_sprite_color(true, false), POISON_SPRITE_COLOR);
     }
 
     #[test]
     fn normal_food_gets_untouched_white_tint() {
-        assert_eq!(food_sprite_color(false), Color::WHITE);
+        assert_eq!(food_sprite_color(false, false), Color::WHITE);
+    }
+
+    #[test]
+    fn colorblind_palette_swaps_only_the_poison_tint() {
+        assert_eq!(food_sprite_color(true, true), COLORBLIND_POISON_SPRITE_COLOR);
+        assert_eq!(food_sprite_color(false, true), Color::WHITE);
+    }
+
+    #[test]
+    fn poison_only_ends_the_run_at_minimum_size() {
+        let food = FoodTunables::default();
+        assert!(!poison_ends_run(food.min_snake_size + 1.0, &food));
+        assert!(poison_ends_run(food.min_snake_size, &food));
     }
 
     #[test]
     fn food_count_matches_today_at_and_below_starting_size() {
-        assert_eq!(target_food_count(STARTING_SNAKE_SIZE), BASE_FOOD_COUNT);
-        assert_eq!(target_food_count(0.0), BASE_FOOD_COUNT);
+        let tunables = Tunables::default();
+        assert_eq!(target_food_count(tunables.creature.starting_size, &tunables), 5);
+        assert_eq!(target_food_count(0.0, &tunables), 5);
     }
 
     #[test]
     fn food_count_grows_beyond_starting_size() {
-        assert_eq!(target_food_count(STARTING_SNAKE_SIZE + SIZE_PER_EXTRA_FOOD * 2.0), BASE_FOOD_COUNT + 2);
+        let tunables = Tunables::default();
+        assert_eq!(target_food_count(tunables.creature.starting_size + tunables.food.size_per_extra_food * 2.0, &tunables), 7);
     }
 
     #[test]
     fn food_count_is_capped_at_max() {
-        assert_eq!(target_food_count(STARTING_SNAKE_SIZE + SIZE_PER_EXTRA_FOOD * 1000.0), MAX_FOOD_COUNT);
+        let tunables = Tunables::default();
+        assert_eq!(target_food_count(tunables.creature.starting_size + tunables.food.size_per_extra_food * 1000.0, &tunables), 20);
     }
 }
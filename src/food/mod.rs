@@ -3,24 +3,31 @@ use bevy::prelude::*;
 
 mod food_item;
 mod bound;
-mod score;
+pub(crate) mod score;
+
+pub use food_item::{Food, food_sprite_color, target_food_count};
+pub use bound::Bound;
 
 use bound::BoundPlugin;
 use score::ScorePlugin;
-use crate::model::game_model::AppState;
+use crate::model::game_model::{AppState, PlayState};
+use crate::game_flow::despawn_all;
+use crate::game_rng::reseed_game_rng;
+use crate::settings::Settings;
 
 pub struct FoodPlugin;
 
-// Growth is measured from the snake's starting size (snake_model_new), so nothing
-// changes until the creature actually grows beyond it. Shared by bound.rs (play-area
-// radius) and food_item.rs (food count).
-const STARTING_SNAKE_SIZE: f32 = 5.0;
-
 impl Plugin for FoodPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((BoundPlugin, ScorePlugin));
-        app.add_systems(OnEnter(AppState::Playing), food_item::food_start);
-        app.add_systems(Update, food_item::food_update.run_if(in_state(AppState::Playing)));
-        app.add_systems(Update, food_item::ensure_food_capacity.run_if(in_state(AppState::Playing)));
+        app.add_systems(OnEnter(AppState::Playing), food_item::food_start.after(reseed_game_rng));
+        app.add_systems(Update, (
+            food_item::food_collision,
+            (food_item::apply_food_growth, food_item::respawn_eaten_food),
+            (food_item::ensure_food_capacity, food_item::spawn_severed_food, food_item::food_movement),
+            food_item::draw_food_hitboxes,
+        ).chain().run_if(in_state(PlayState::Running)));
+        app.add_systems(Update, food_item::food_palette_update.run_if(resource_changed::<Settings>));
+        app.add_systems(OnExit(AppState::Playing), despawn_all::<food_item::Food>);
     }
 }
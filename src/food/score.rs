@@ -1,47 +1,196 @@
-use bevy::app::{App, Plugin, Startup};
+use bevy::app::{App, Plugin, Update};
 use bevy::prelude::*;
 
+use crate::model::game_model::{AppState, PlayState};
+use crate::gameplay_events::{FoodEaten, FoodKind, PoisonEaten};
+use crate::game_clock::GameClock;
+use crate::tunables::{ScoreTunables, Tunables};
+
 pub struct ScorePlugin;
 
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, score_start);
+        app.init_resource::<Score>();
+        app.add_systems(OnEnter(AppState::Playing), reset_score);
+        app.add_systems(Update, (
+            score_food_eaten.after(super::food_item::food_collision),
+            combo_update,
+        ).run_if(in_state(PlayState::Running)));
     }
 }
 
-// pub(super) rather than private: food_item.rs (a sibling module) needs to name this
-// type for its Query<(&mut Text, &mut Score)> parameter, even though it never touches
-// the field directly - only add_point below does that.
-#[derive(Component)]
-pub(super) struct Score {
-    score_num: i32
+// The current run's score. A resource rather than a component on the score text:
+// it's displayed by the HUD (hud.rs), and high_score tracks the run's result through
+// points(). Only score_food_eaten/combo_update below change it.
+#[derive(Resource, Default)]
+pub(crate) struct Score {
+    score_num: i32,
+    combo: Combo,
+}
+
+impl Score {
+    pub(crate) fn points(&self) -> i32 {
+        self.score_num
+    }
+
+    pub(crate) fn combo(&self) -> Combo {
+        self.combo
+    }
+
+    // The score line as the HUD shows it, combo included while a streak is running.
+    pub(crate) fn text(&self) -> String {
+        score_text(self.score_num, self.combo)
+    }
+}
+
+// Consecutive-food streak. The multiplier applies to the food that raised it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Combo {
+    pub(crate) multiplier: i32,
+    // seconds left to eat the next normal food and keep the streak going; 0 = no streak
+    pub(crate) window_remaining: f32,
+}
+
+impl Default for Combo {
+    fn default() -> Self {
+        Self { multiplier: 1, window_remaining: 0.0 }
+    }
 }
 
-fn score_start(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let font = asset_server.load("MovistarTextRegular.ttf");
-    let text_style = TextStyle {
-        font: font.clone(),
-        font_size: 50.0,
-        ..default()
+// Scoring rules, kept pure so they can be tested without a running app.
+
+// Normal food eaten: within the window the multiplier goes up (capped), otherwise the
+// streak starts over at x1. Either way the window restarts. Returns the points earned.
+// Window length and cap come from Tunables.score.
+fn combo_food_eaten(combo: Combo, rules: &ScoreTunables) -> (Combo, i32) {
+    let multiplier = if combo.window_remaining > 0.0 {
+        (combo.multiplier + 1).min(rules.max_combo_multiplier)
+    } else {
+        1
     };
-    let text_justification = JustifyText::Center;
-
-    commands.spawn((
-        Text2dBundle {
-            text: Text::from_section("no score", text_style.clone())
-                .with_justify(text_justification),
-            transform: Transform::from_xyz(0.0, 200.0, 0.0),
-            ..default()
-        },
-        Score { score_num: 0 }
-    ));
-}
-
-// Called from food.rs when normal (non-poisonous) food is eaten.
-pub(super) fn add_point(score_query: &mut Query<(&mut Text, &mut Score)>) {
-    for (mut text, mut score) in score_query {
-        score.score_num += 1;
-        let score_string = score.score_num.to_string();
-        text.sections[0].value = format!("Score: {score_string}");
+    (Combo { multiplier, window_remaining: rules.combo_window_seconds }, multiplier)
+}
+
+// Poison eaten: the streak is lost straight away.
+fn combo_poison_eaten(_combo: Combo) -> Combo {
+    Combo::default()
+}
+
+// Time passes: the window runs down, and the streak is lost once it lapses.
+fn combo_tick(combo: Combo, delta_seconds: f32) -> Combo {
+    let window_remaining = combo.window_remaining - delta_seconds;
+    if window_remaining <= 0.0 {
+        Combo::default()
+    } else {
+        Combo { window_remaining, ..combo }
+    }
+}
+
+// What the score text shows: the combo only while a streak above x1 is running.
+fn score_text(score_num: i32, combo: Combo) -> String {
+    if combo.multiplier > 1 && combo.window_remaining > 0.0 {
+        format!("Score: {score_num}  x{} ({:.1}s)", combo.multiplier, combo.window_remaining)
+    } else {
+        format!("Score: {score_num}")
+    }
+}
+
+fn reset_score(mut score: ResMut<Score>) {
+    *score = Score::default();
+}
+
+fn add_point(score: &mut Score, rules: &ScoreTunables) {
+    let (combo, points) = combo_food_eaten(score.combo, rules);
+    score.combo = combo;
+    score.score_num += points;
+}
+
+fn break_combo(score: &mut Score) {
+    score.combo = combo_poison_eaten(score.combo);
+}
+
+// Normal food scores (with the combo), poison breaks the streak.
+fn score_food_eaten(
+    mut score: ResMut<Score>,
+    mut food_eaten_events: EventReader<FoodEaten>,
+    mut poison_eaten_events: EventReader<PoisonEaten>,
+    tunables: Res<Tunables>,
+) {
+    for event in food_eaten_events.read() {
+        if event.kind == FoodKind::Normal {
+            add_point(&mut score, &tunables.score);
+        }
+    }
+    for _ in poison_eaten_events.read() {
+        break_combo(&mut score);
+    }
+}
+
+// Runs the combo window down every frame.
+fn combo_update(mut score: ResMut<Score>, clock: Res<GameClock>) {
+    if score.combo == Combo::default() {
+        return; // no streak running - don't mark the resource changed
+    }
+    score.combo = combo_tick(score.combo, clock.delta_seconds());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RULES: ScoreTunables = ScoreTunables { combo_window_seconds: 2.0, max_combo_multiplier: 5 };
+
+    #[test]
+    fn first_food_scores_one_and_opens_the_window() {
+        let (combo, points) = combo_food_eaten(Combo::default(), &RULES);
+        assert_eq!(points, 1);
+        assert_eq!(combo.multiplier, 1);
+        assert_eq!(combo.window_remaining, RULES.combo_window_seconds);
+    }
+
+    #[test]
+    fn food_within_the_window_raises_the_multiplier() {
+        let (combo, _) = combo_food_eaten(Combo::default(), &RULES);
+        let combo = combo_tick(combo, RULES.combo_window_seconds / 2.0);
+        let (combo, points) = combo_food_eaten(combo, &RULES);
+        assert_eq!(points, 2);
+        assert_eq!(combo.multiplier, 2);
+        assert_eq!(combo.window_remaining, RULES.combo_window_seconds);
+    }
+
+    #[test]
+    fn multiplier_is_capped() {
+        let mut combo = Combo::default();
+        let mut points = 0;
+        for _ in 0..RULES.max_combo_multiplier + 3 {
+            (combo, points) = combo_food_eaten(combo, &RULES);
+        }
+        assert_eq!(points, RULES.max_combo_multiplier);
+    }
+
+    #[test]
+    fn lapsed_window_resets_the_streak() {
+        let (combo, _) = combo_food_eaten(Combo::default(), &RULES);
+        let (combo, _) = combo_food_eaten(combo, &RULES);
+        let combo = combo_tick(combo, RULES.combo_window_seconds);
+        assert_eq!(combo, Combo::default());
+        let (_, points) = combo_food_eaten(combo, &RULES);
+        assert_eq!(points, 1);
+    }
+
+    #[test]
+    fn poison_resets_the_streak() {
+        let (combo, _) = combo_food_eaten(Combo::default(), &RULES);
+        let (combo, _) = combo_food_eaten(combo, &RULES);
+        let combo = combo_poison_eaten(combo);
+        let (_, points) = combo_food_eaten(combo, &RULES);
+        assert_eq!(points, 1);
+    }
+
+    #[test]
+    fn score_text_shows_combo_only_during_a_streak() {
+        assert_eq!(score_text(3, Combo::default()), "Score: 3");
+        assert_eq!(score_text(3, Combo { multiplier: 1, window_remaining: 1.0 }), "Score: 3");
+        assert_eq!(score_text(7, Combo { multiplier: 3, window_remaining: 1.25 }), "Score: 7  x3 (1.2s)");
     }
 }
@@ -0,0 +1,150 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+
+#[cfg(feature = "debug")]
+use crate::input_map::{InputAction, InputMap};
+
+pub struct GameClockPlugin;
+
+impl Plugin for GameClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameClock>();
+        // ticks once per frame after input is read, so everything in Update sees this frame's step
+        #[cfg(feature = "debug")]
+        app.add_systems(PreUpdate, (game_clock_keys, tick_game_clock).chain().after(InputSystem));
+        #[cfg(not(feature = "debug"))]
+        app.add_systems(PreUpdate, tick_game_clock.after(InputSystem));
+    }
+}
+
+// Simulation time for gameplay. Systems that move or age things read
+// `clock.delta_seconds()` instead of `time.delta_seconds()`, so the whole simulation can be
+// slowed down, sped up, or stopped and walked through one tick at a time - handy for
+// watching the body follow the trace round a sharp corner. Menus, UI and music keep
+// running on real time.
+//
+// Its pause is a debug freeze, separate from the pause menu: the game stays in
+// PlayState::Running with no overlay, it just doesn't advance.
+
+pub const MIN_TIME_SCALE: f32 = 0.1;
+pub const MAX_TIME_SCALE: f32 = 4.0;
+// length of one frame step at 1x; scaled like everything else, so slow motion steps finer
+const STEP_SECONDS: f32 = 1.0 / 60.0;
+
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct GameClock {
+    scale: f32,
+    paused: bool,
+    step_requested: bool,
+    // simulation seconds covered by the current frame
+    delta: f32,
+}
+
+impl Default for GameClock {
+    fn default() -> Self {
+        Self { scale: 1.0, paused: false, step_requested: false, delta: 0.0 }
+    }
+}
+
+impl GameClock {
+    pub fn delta_seconds(&self) -> f32 {
+        self.delta
+    }
+
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f32) {
+        self.scale = scale.clamp(MIN_TIME_SCALE, MAX_TIME_SCALE);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+        self.step_requested = false;
+    }
+
+    // Advances exactly one tick on the next frame. Only does anything while paused.
+    pub fn request_step(&mut self) {
+        self.step_requested = self.paused;
+    }
+
+    // Works out this frame's simulation delta from the real one.
+    pub fn tick(&mut self, real_delta_seconds: f32) {
+        self.delta = if !self.paused {
+            real_delta_seconds * self.scale
+        } else if self.step_requested {
+            STEP_SECONDS * self.scale
+        } else {
+            0.0
+        };
+        self.step_requested = false;
+    }
+}
+
+fn tick_game_clock(mut clock: ResMut<GameClock>, time: Res<Time>) {
+    clock.tick(time.delta_seconds());
+}
+
+// "[" / "]" halve / double the speed, "P" freezes the clock, "." steps one tick while frozen.
+#[cfg(feature = "debug")]
+fn game_clock_keys(keyboard_input: Res<ButtonInput<KeyCode>>, input_map: Res<InputMap>, mut clock: ResMut<GameClock>) {
+    if input_map.just_pressed(InputAction::SlowDownTime, &keyboard_input) {
+        let scale = clock.scale() / 2.0;
+        clock.set_scale(scale);
+    }
+    if input_map.just_pressed(InputAction::SpeedUpTime, &keyboard_input) {
+        let scale = clock.scale() * 2.0;
+        clock.set_scale(scale);
+    }
+    if input_map.just_pressed(InputAction::ToggleTimePause, &keyboard_input) {
+        let paused = !clock.is_paused();
+        clock.set_paused(paused);
+    }
+    if input_map.just_pressed(InputAction::StepFrame, &keyboard_input) {
+        clock.request_step();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_multiplies_real_time_and_is_clamped() {
+        let mut clock = GameClock::default();
+        clock.set_scale(0.5);
+        clock.tick(0.1);
+        assert_eq!(clock.delta_seconds(), 0.05);
+        clock.set_scale(100.0);
+        assert_eq!(clock.scale(), MAX_TIME_SCALE);
+        clock.set_scale(0.0);
+        assert_eq!(clock.scale(), MIN_TIME_SCALE);
+    }
+
+    #[test]
+    fn paused_clock_only_moves_one_step_per_request() {
+        let mut clock = GameClock::default();
+        clock.set_paused(true);
+        clock.tick(0.1);
+        assert_eq!(clock.delta_seconds(), 0.0);
+        clock.request_step();
+        clock.tick(0.1);
+        assert_eq!(clock.delta_seconds(), STEP_SECONDS);
+        clock.tick(0.1);
+        assert_eq!(clock.delta_seconds(), 0.0);
+    }
+
+    #[test]
+    fn step_requests_are_ignored_while_running() {
+        let mut clock = GameClock::default();
+        clock.request_step();
+        clock.set_paused(true);
+        clock.tick(0.1);
+        assert_eq!(clock.delta_seconds(), 0.0);
+    }
+}
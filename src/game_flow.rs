@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+
+use crate::input_map::{InputAction, InputMap};
+use crate::model::game_model::{AppState, PlayState};
+use crate::ui::button::{MenuAction, MenuButtonPressed};
+
+pub struct GameFlowPlugin;
+
+impl Plugin for GameFlowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<AppState>();
+        app.add_sub_state::<PlayState>();
+        app.add_systems(OnEnter(AppState::Restarting), finish_restart);
+        app.add_systems(Update, toggle_pause.run_if(in_state(AppState::Playing)));
+        app.add_systems(Update, menu_actions);
+    }
+}
+
+// Despawns every entity with component T (and its children). Plugins register this on
+// OnExit(AppState::Playing) for whatever they spawned when the run started.
+pub fn despawn_all<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Second half of a restart: OnExit(Playing) has already torn the old run down on the
+// way into Restarting, so going back to Playing spawns a fresh one.
+fn finish_restart(mut next_app_state: ResMut<NextState<AppState>>) {
+    next_app_state.set(AppState::Playing);
+}
+
+// Escape (InputAction::Pause) freezes the run and shows the pause menu (pause_menu.rs), and resumes it again.
+fn toggle_pause(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    input_map: Res<InputMap>,
+    play_state: Res<State<PlayState>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    if input_map.just_pressed(InputAction::Pause, &keyboard_input) {
+        next_play_state.set(match play_state.get() {
+            PlayState::Running => PlayState::Paused,
+            PlayState::Paused => PlayState::Running,
+        });
+    }
+}
+
+// Carries out menu button presses (ui/button.rs) from any menu. Start moves AppState to
+// Playing, which is what actually unblocks the snake/food gameplay systems (they're
+// registered with .run_if(in_state(PlayState::Running))).
+fn menu_actions(
+    mut pressed_events: EventReader<MenuButtonPressed>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_play_state: ResMut<NextState<PlayState>>,
+) {
+    for MenuButtonPressed(action) in pressed_events.read() {
+        match action {
+            MenuAction::StartGame => next_app_state.set(AppState::Playing),
+            MenuAction::Resume => next_play_state.set(PlayState::Running),
+            MenuAction::Restart => next_app_state.set(AppState::Restarting),
+            // the settings screen opens over the current menu without changing state (settings/menu.rs)
+            MenuAction::Settings | MenuAction::CloseSettings => {}
+            MenuAction::QuitToMenu => next_app_state.set(AppState::MainMenu),
+        }
+    }
+}
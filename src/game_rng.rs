@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::model::game_model::AppState;
+
+pub struct GameRngPlugin;
+
+impl Plugin for GameRngPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameRng::from_seed(rand::thread_rng().gen()));
+        app.add_systems(OnEnter(AppState::Playing), reseed_game_rng);
+    }
+}
+
+/// Single source of randomness for gameplay (food placement, poison rolls, ...).
+/// Every run gets its own seed, recorded with the high score, so a run can be replayed/simulated again.
+#[derive(Resource)]
+pub struct GameRng {
+    pub seed: u64,
+    pub rng: StdRng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self { seed, rng: StdRng::seed_from_u64(seed) }
+    }
+}
+
+/// When present, every run is seeded with this instead of a fresh random seed - the
+/// simulator (src/bin/simulator.rs) uses it to replay the same seeds.
+#[derive(Resource)]
+pub struct FixedSeed(pub u64);
+
+// Rolls a fresh seed whenever a run starts. Systems that spawn things randomly on
+// OnEnter(AppState::Playing) must run .after(reseed_game_rng).
+pub fn reseed_game_rng(mut game_rng: ResMut<GameRng>, fixed_seed: Option<Res<FixedSeed>>) {
+    let seed = fixed_seed.map(|fixed_seed| fixed_seed.0).unwrap_or_else(|| rand::thread_rng().gen());
+    *game_rng = GameRng::from_seed(seed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_sequence() {
+        let mut a = GameRng::from_seed(42);
+        let mut b = GameRng::from_seed(42);
+        for _ in 0..10 {
+            assert_eq!(a.rng.gen::<u32>(), b.rng.gen::<u32>());
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+
+use crate::snake_model::SnakeSpineNodeType;
+
+pub struct GameplayEventsPlugin;
+
+impl Plugin for GameplayEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<FoodEaten>();
+        app.add_event::<PoisonEaten>();
+        app.add_event::<TierChanged>();
+        app.add_event::<SizeChanged>();
+        app.add_event::<CreatureSevered>();
+    }
+}
+
+// Moments in a run that more than one plugin cares about. The system that detects a
+// moment sends the event; everything that follows from it (growth, score, respawning the
+// food, sounds, ...) is a separate system reading it, so new reactions don't have to be
+// wired into the gameplay code that noticed it.
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum FoodKind {
+    Normal,
+    Poison,
+}
+
+impl FoodKind {
+    pub fn of(is_poisonous: bool) -> Self {
+        if is_poisonous { FoodKind::Poison } else { FoodKind::Normal }
+    }
+}
+
+/// `creature` ate the `food` entity at `pos`. Sent for every kind of food, poison included.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct FoodEaten {
+    pub creature: Entity,
+    pub food: Entity,
+    pub kind: FoodKind,
+    pub pos: Vec2,
+}
+
+/// `creature` ate poisonous food at `pos`. Sent alongside its FoodEaten, for the reactions
+/// that are only about poison (combo break, poison sound, ...).
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PoisonEaten {
+    pub creature: Entity,
+    pub pos: Vec2,
+}
+
+/// The creature's evolution_tier changed - up when it grew past a threshold, down when poison
+/// shrank it. Raised by update_evolution_transition.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TierChanged {
+    pub from: SnakeSpineNodeType,
+    pub to: SnakeSpineNodeType,
+}
+
+/// `creature`'s size went from `from` to `to`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SizeChanged {
+    pub creature: Entity,
+    pub from: f32,
+    pub to: f32,
+}
+
+/// `creature` lost its body from `segment` (index into SnakeModel.body) back; the lost
+/// segments were at `positions`.
+#[derive(Event, Debug, Clone)]
+pub struct CreatureSevered {
+    pub creature: Entity,
+    pub segment: usize,
+    pub positions: Vec<Vec2>,
+}
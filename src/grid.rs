@@ -1,75 +1,192 @@
 
 use bevy::prelude::*;
+#[cfg(feature = "debug")]
 use bevy::{
     color::palettes::basic::*,
 };
-use std::f32;
 use bevy::app::{App, Plugin};
+#[cfg(feature = "debug")]
 use bevy::color::palettes::css::GREY;
 
-pub struct VisualDiagnosticPlugin;
+#[cfg(feature = "debug")]
+use crate::input_map::{InputAction, InputMap};
+#[cfg(feature = "debug")]
+use crate::settings::Settings;
 
-// Toggled by pressing "1" (see draw_grid below). When enabled, draws a debug grid
-// and switches on gizmo drawing for hitboxes/paths in other systems (snake, food, bound).
-#[derive(Component)]
-pub struct GridVisualDiagnostic {
-    enabled: bool,
-    cell_size: f32,
-}
+pub struct VisualDiagnosticPlugin;
 
 impl Plugin for VisualDiagnosticPlugin{
     fn build (&self, app: &mut App) {
-        app.add_systems(Startup, grid_start);
-        app.add_systems(Update, draw_grid);
+        app.init_resource::<DebugDraw>();
+        // without the "debug" feature DebugDraw stays off and nothing here is compiled in
+        #[cfg(feature = "debug")]
+        {
+            app.add_systems(Update, (debug_draw_keys, draw_grid).chain());
+            app.add_systems(Update, apply_debug_overlay_setting.run_if(resource_exists_and_changed::<Settings>));
+        }
     }
 }
 
-fn grid_start (mut commands: Commands) {
-    commands.spawn(GridVisualDiagnostic{
-        enabled: false,
-        cell_size: 50.0
-    });
+// The kinds of debug gizmos. Each module checks its own category before drawing, so they
+// can be switched on one at a time from number keys 3-9 or the debug inspector.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum DebugDrawCategory {
+    Grid,
+    TracePolyline,
+    SegmentCircles,
+    HeadHitbox,
+    FoodHitboxes,
+    Bound,
+    SplineTangents,
+}
+
+impl DebugDrawCategory {
+    pub const ALL: [DebugDrawCategory; 7] = [
+        DebugDrawCategory::Grid,
+        DebugDrawCategory::TracePolyline,
+        DebugDrawCategory::SegmentCircles,
+        DebugDrawCategory::HeadHitbox,
+        DebugDrawCategory::FoodHitboxes,
+        DebugDrawCategory::Bound,
+        DebugDrawCategory::SplineTangents,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            DebugDrawCategory::Grid => "grid",
+            DebugDrawCategory::TracePolyline => "trace",
+            DebugDrawCategory::SegmentCircles => "segments",
+            DebugDrawCategory::HeadHitbox => "head hitbox",
+            DebugDrawCategory::FoodHitboxes => "food hitboxes",
+            DebugDrawCategory::Bound => "bound",
+            DebugDrawCategory::SplineTangents => "tangents",
+        }
+    }
 }
 
-/// Visual diagnostics enabled when we need to draw extra entity shapes using gizmos to help to debug game.
+/// Which debug gizmos get drawn. `enabled` is the master switch (key "1", InputAction::ToggleDiagnostics,
+/// and the settings screen's "Debug overlay"); within it every category can be turned off on its own.
 /// Without visual diagnostics gizmos will not be used and we will see only SpriteBundles.
-/// Drawing gizmos with SpriteBundles together helps to test the game.
-pub fn grid_draw_visual_diagnostics_info(query: &Query<&GridVisualDiagnostic>) -> bool {
-    for item in query {
-        if item.enabled { 
-            return true;
+#[derive(Resource, Debug, Clone, PartialEq, Default)]
+pub struct DebugDraw {
+    pub enabled: bool,
+    hidden: Vec<DebugDrawCategory>,
+}
+
+impl DebugDraw {
+    // Whether gizmos of `category` should be drawn right now.
+    pub fn draws(&self, category: DebugDrawCategory) -> bool {
+        cfg!(feature = "debug") && self.enabled && self.shows(category)
+    }
+
+    // The category's own switch, regardless of the master one.
+    pub fn shows(&self, category: DebugDrawCategory) -> bool {
+        !self.hidden.contains(&category)
+    }
+
+    pub fn set_shown(&mut self, category: DebugDrawCategory, shown: bool) {
+        self.hidden.retain(|c| *c != category);
+        if !shown {
+            self.hidden.push(category);
+        }
+    }
+
+    pub fn toggle(&mut self, category: DebugDrawCategory) {
+        self.set_shown(category, !self.shows(category));
+    }
+}
+
+// The settings screen's "Debug overlay" is the state diagnostics start in; key "1" still
+// flips it during play until the setting is changed again.
+#[cfg(feature = "debug")]
+fn apply_debug_overlay_setting(settings: Res<Settings>, mut applied: Local<Option<bool>>, mut debug_draw: ResMut<DebugDraw>) {
+    // other settings changing (a volume slider, say) mustn't undo a key "1" toggle
+    if *applied == Some(settings.debug_overlay) {
+        return;
+    }
+    *applied = Some(settings.debug_overlay);
+    debug_draw.enabled = settings.debug_overlay;
+}
+
+#[cfg(feature = "debug")]
+fn debug_draw_keys(keyboard_input: Res<ButtonInput<KeyCode>>, input_map: Res<InputMap>, mut debug_draw: ResMut<DebugDraw>) {
+    if input_map.just_pressed(InputAction::ToggleDiagnostics, &keyboard_input) {
+        debug_draw.enabled = !debug_draw.enabled;
+    }
+    for category in DebugDrawCategory::ALL {
+        if input_map.just_pressed(InputAction::ToggleDebugDraw(category), &keyboard_input) {
+            debug_draw.toggle(category);
         }
     }
-    return false;
 }
 
 // Half-width/height the grid lines span - how far out the debug grid reaches.
+#[cfg(feature = "debug")]
 const GRID_EXTENT: f32 = 5000.0;
+#[cfg(feature = "debug")]
+const GRID_CELL_SIZE: f32 = 50.0;
 
-fn draw_grid(
-    mut gizmos: Gizmos,
-    mut grid_query: Query<&mut GridVisualDiagnostic>,
-    keyboard_input: Res<ButtonInput<KeyCode>>
-){
-    for mut grid in &mut grid_query {
-        if keyboard_input.just_pressed(KeyCode::Digit1) {
-            grid.enabled = !grid.enabled;
-        }
+#[cfg(feature = "debug")]
+fn draw_grid(mut gizmos: Gizmos, debug_draw: Res<DebugDraw>) {
+    if !debug_draw.draws(DebugDrawCategory::Grid) {
+        return;
+    }
+    let line_count = (2.0 * GRID_EXTENT / GRID_CELL_SIZE) as i32;
+    for i in 1..line_count {
+        let start_pos: Vec2 = Vec2::new(-GRID_EXTENT, -GRID_EXTENT + (i as f32 * GRID_CELL_SIZE));
+        let end_pos: Vec2 = Vec2::new(GRID_EXTENT, -GRID_EXTENT + (i as f32 * GRID_CELL_SIZE));
+        gizmos.line_2d(start_pos, end_pos, GREY);
+    }
+    for i in 1..line_count {
+        let start_pos: Vec2 = Vec2::new(-GRID_EXTENT + (i as f32 * GRID_CELL_SIZE), -GRID_EXTENT);
+        let end_pos: Vec2 = Vec2::new(-GRID_EXTENT + (i as f32 * GRID_CELL_SIZE), GRID_EXTENT);
+        gizmos.line_2d(start_pos, end_pos, GREY);
+    }
+    gizmos.line_2d(Vec2::new(-5.0, -5.0), Vec2::new(5.0, 5.0), RED);
+    gizmos.line_2d(Vec2::new(5.0, -5.0), Vec2::new(-5.0, 5.0), RED);
+}
 
-        if grid.enabled {
-            let line_count = (2.0 * GRID_EXTENT / grid.cell_size) as i32;
-            for i in 1..line_count {
-                let start_pos: Vec2 = Vec2::new(-GRID_EXTENT, -GRID_EXTENT + (i as f32 * grid.cell_size));
-                let end_pos: Vec2 = Vec2::new(GRID_EXTENT, -GRID_EXTENT + (i as f32 * grid.cell_size));
-                gizmos.line_2d(start_pos, end_pos, GREY);
-            }
-            for i in 1..line_count {
-                let start_pos: Vec2 = Vec2::new(-GRID_EXTENT + (i as f32 * grid.cell_size), -GRID_EXTENT);
-                let end_pos: Vec2 = Vec2::new(-GRID_EXTENT + (i as f32 * grid.cell_size), GRID_EXTENT);
-                gizmos.line_2d(start_pos, end_pos, GREY);
-            }
-            gizmos.line_2d(Vec2::new(-5.0, -5.0), Vec2::new(5.0, 5.0), RED);
-            gizmos.line_2d(Vec2::new(5.0, -5.0), Vec2::new(-5.0, 5.0), RED);
-        }
+#[cfg(all(test, feature = "debug"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categories_only_draw_with_the_master_switch_on() {
+        let mut debug_draw = DebugDraw::default();
+        assert!(!debug_draw.draws(DebugDrawCategory::Grid));
+        debug_draw.enabled = true;
+        assert!(DebugDrawCategory::ALL.iter().all(|c| debug_draw.draws(*c)));
+    }
+
+    #[test]
+    fn toggling_a_category_leaves_the_others_alone() {
+        let mut debug_draw = DebugDraw { enabled: true, ..DebugDraw::default() };
+        debug_draw.toggle(DebugDrawCategory::Bound);
+        assert!(!debug_draw.draws(DebugDrawCategory::Bound));
+        assert!(debug_draw.draws(DebugDrawCategory::FoodHitboxes));
+        debug_draw.toggle(DebugDrawCategory::Bound);
+        assert!(debug_draw.draws(DebugDrawCategory::Bound));
+    }
+
+    #[test]
+    fn other_settings_keep_the_runtime_toggle() {
+        let mut app = App::new();
+        app.insert_resource(Settings::default());
+        app.init_resource::<DebugDraw>();
+        app.add_systems(Update, apply_debug_overlay_setting.run_if(resource_exists_and_changed::<Settings>));
+        app.update();
+        assert!(!app.world().resource::<DebugDraw>().enabled);
+
+        // key "1" during play, then a volume change
+        app.world_mut().resource_mut::<DebugDraw>().enabled = true;
+        app.world_mut().resource_mut::<Settings>().sfx_volume = 0.1;
+        app.update();
+        assert!(app.world().resource::<DebugDraw>().enabled);
+
+        // changing the setting itself still wins
+        app.world_mut().resource_mut::<DebugDraw>().enabled = false;
+        app.world_mut().resource_mut::<Settings>().debug_overlay = true;
+        app.update();
+        assert!(app.world().resource::<DebugDraw>().enabled);
     }
 }
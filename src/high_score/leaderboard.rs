@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use super::store::{HighScoreEntry, qualifies};
+use super::{HighScoreTable, RunStats};
+use crate::creature_body_evolution::tier_name;
+use crate::model::game_model::{AppState, TheGame};
+use crate::ui::button::{MenuAction, spawn_menu_button};
+use crate::ui::text_input::{TextFilter, TextInputSubmitted, spawn_text_input};
+use crate::ui::theme::{HIGHLIGHT_TEXT_COLOR, TEXT_COLOR, TITLE_FONT_SIZE, column, text_style};
+
+// Leaderboard panel on the main menu, and the game over screen where a qualifying run
+// enters its initials before being added to the table. Both hang off TheGame.root_ui_node.
+// Once the leaderboard is up, the game over screen offers a restart or the way back to the menu.
+
+const MAX_INITIALS: usize = 3;
+
+#[derive(Component)]
+pub(super) struct MainMenuLeaderboard;
+
+#[derive(Component)]
+pub(super) struct GameOverView;
+
+// The part of the game over screen that starts as the initials prompt and is
+// swapped for the leaderboard once initials are confirmed.
+#[derive(Component)]
+pub(super) struct InitialsEntry;
+
+#[derive(Component)]
+pub(super) struct InitialsInput;
+
+// Seconds as m:ss.
+pub fn format_duration(seconds: f32) -> String {
+    let total = seconds.max(0.0) as u32;
+    format!("{}:{:02}", total / 60, total % 60)
+}
+
+fn leaderboard_row(rank: usize, entry: &HighScoreEntry) -> String {
+    format!(
+        "{:>2}. {:<3}  {:>5}   size {:>3}   {:<6}  {}",
+        rank + 1,
+        entry.initials,
+        entry.score,
+        entry.max_size as i32,
+        tier_name(entry.highest_tier),
+        format_duration(entry.duration_seconds),
+    )
+}
+
+// Title plus one line per entry; `highlight` marks the row the player just earned.
+fn spawn_leaderboard(parent: &mut ChildBuilder, asset_server: &AssetServer, entries: &[HighScoreEntry], highlight: Option<usize>) {
+    parent.spawn(column()).with_children(|list| {
+        list.spawn(TextBundle::from_section("High Scores", text_style(asset_server, 32.0, TEXT_COLOR)));
+        if entries.is_empty() {
+            list.spawn(TextBundle::from_section("No scores yet", text_style(asset_server, 20.0, TEXT_COLOR)));
+        }
+        for (rank, entry) in entries.iter().enumerate() {
+            let color = if highlight == Some(rank) { HIGHLIGHT_TEXT_COLOR } else { TEXT_COLOR };
+            list.spawn(TextBundle::from_section(leaderboard_row(rank, entry), text_style(asset_server, 20.0, color)));
+        }
+    });
+}
+
+fn spawn_controls(parent: &mut ChildBuilder, asset_server: &AssetServer) {
+    parent.spawn(column()).with_children(|controls| {
+        spawn_menu_button(controls, asset_server, "Play Again", MenuAction::Restart);
+        spawn_menu_button(controls, asset_server, "Main Menu", MenuAction::QuitToMenu);
+        controls.spawn(TextBundle::from_section("Enter: play again    Esc: main menu", text_style(asset_server, 20.0, TEXT_COLOR)));
+    });
+}
+
+pub(super) fn spawn_main_menu_leaderboard(
+    mut commands: Commands,
+    the_game_query: Query<&TheGame>,
+    table: Res<HighScoreTable>,
+    asset_server: Res<AssetServer>,
+) {
+    let Ok(the_game) = the_game_query.get_single() else { return; };
+    let view = commands.spawn((column(), MainMenuLeaderboard)).with_children(|parent| {
+        spawn_leaderboard(parent, &asset_server, &table.entries, None);
+    }).id();
+    commands.entity(the_game.root_ui_node).add_child(view);
+}
+
+pub(super) fn spawn_game_over_view(
+    mut commands: Commands,
+    the_game_query: Query<&TheGame>,
+    table: Res<HighScoreTable>,
+    stats: Res<RunStats>,
+    asset_server: Res<AssetServer>,
+) {
+    let Ok(the_game) = the_game_query.get_single() else { return; };
+    let summary = format!(
+        "Score {}   size {}   {}   {}",
+        stats.score,
+        stats.max_size as i32,
+        tier_name(stats.highest_tier),
+        format_duration(stats.duration_seconds),
+    );
+    let view = commands.spawn((column(), GameOverView)).with_children(|parent| {
+        parent.spawn(TextBundle::from_section("Game Over", text_style(&asset_server, TITLE_FONT_SIZE, TEXT_COLOR)));
+        parent.spawn(TextBundle::from_section(summary, text_style(&asset_server, 24.0, TEXT_COLOR)));
+
+        if qualifies(&table.entries, stats.score) {
+            parent.spawn((column(), InitialsEntry)).with_children(|entry| {
+                entry.spawn(TextBundle::from_section("New high score! Enter your initials", text_style(&asset_server, 24.0, HIGHLIGHT_TEXT_COLOR)));
+                spawn_text_input(entry, &asset_server, MAX_INITIALS, TextFilter::UppercaseLetters).insert(InitialsInput);
+                entry.spawn(TextBundle::from_section("Enter to confirm", text_style(&asset_server, 18.0, TEXT_COLOR)));
+            });
+        } else {
+            spawn_leaderboard(parent, &asset_server, &table.entries, None);
+            spawn_controls(parent, &asset_server);
+        }
+    }).id();
+    commands.entity(the_game.root_ui_node).add_child(view);
+}
+
+// Enter restarts, Escape goes back to the main menu - but only once the initials prompt
+// (if any) is done, so typing/confirming initials can't trigger either. Runs before
+// initials_submitted, so the Enter that confirms initials isn't also seen here as a restart.
+pub(super) fn game_over_controls(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    initials_entry_query: Query<(), With<InitialsEntry>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if !initials_entry_query.is_empty() {
+        return;
+    }
+    if keyboard_input.just_pressed(KeyCode::Enter) {
+        next_app_state.set(AppState::Restarting);
+    } else if keyboard_input.just_pressed(KeyCode::Escape) {
+        next_app_state.set(AppState::MainMenu);
+    }
+}
+
+// Confirmed initials (the text input sends them on Enter) save the run to the table and
+// swap the prompt for the leaderboard.
+pub(super) fn initials_submitted(
+    mut commands: Commands,
+    mut submitted_events: EventReader<TextInputSubmitted>,
+    entry_query: Query<Entity, With<InitialsEntry>>,
+    initials_input_query: Query<(), With<InitialsInput>>,
+    mut table: ResMut<HighScoreTable>,
+    stats: Res<RunStats>,
+    asset_server: Res<AssetServer>,
+) {
+    let Ok(entry_entity) = entry_query.get_single() else {
+        submitted_events.clear();
+        return;
+    };
+    if let Some(submitted) = submitted_events.read().filter(|e| initials_input_query.contains(e.entity)).last() {
+        let rank = table.add(stats.to_entry(submitted.value.clone()));
+        commands.entity(entry_entity).despawn_descendants().remove::<InitialsEntry>().with_children(|parent| {
+            spawn_leaderboard(parent, &asset_server, &table.entries, rank);
+            spawn_controls(parent, &asset_server);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duration_formats_as_minutes_and_seconds() {
+        assert_eq!(format_duration(0.0), "0:00");
+        assert_eq!(format_duration(65.7), "1:05");
+        assert_eq!(format_duration(600.0), "10:00");
+    }
+}
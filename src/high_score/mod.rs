@@ -0,0 +1,145 @@
+use std::path::PathBuf;
+use bevy::app::{App, Plugin, Update};
+use bevy::prelude::*;
+
+mod store;
+mod leaderboard;
+
+use store::{HighScoreEntry, default_high_score_path, load_table};
+use crate::creature_body_evolution::tier_rank;
+use crate::food::score::Score;
+use crate::game_rng::{GameRng, reseed_game_rng};
+use crate::model::game_model::{AppState, PlayState};
+use crate::game_flow::despawn_all;
+use crate::settings::spawn_main_menu_settings_button;
+use crate::ui::text_input::text_input_keys;
+use crate::snake_model::{SnakeModel, SnakeSpineNodeType};
+use crate::game_clock::GameClock;
+
+pub struct HighScorePlugin;
+
+impl Plugin for HighScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(HighScoreTable::load());
+        app.init_resource::<RunStats>();
+        app.add_systems(OnEnter(AppState::Playing), reset_run_stats.after(reseed_game_rng));
+        app.add_systems(Update, track_run_stats.run_if(in_state(PlayState::Running)));
+
+        app.add_systems(OnEnter(AppState::MainMenu), leaderboard::spawn_main_menu_leaderboard.after(spawn_main_menu_settings_button));
+        app.add_systems(OnExit(AppState::MainMenu), despawn_all::<leaderboard::MainMenuLeaderboard>);
+        app.add_systems(OnEnter(AppState::GameOver), leaderboard::spawn_game_over_view);
+        app.add_systems(Update, (
+            leaderboard::game_over_controls.before(leaderboard::initials_submitted),
+            leaderboard::initials_submitted.after(text_input_keys),
+        ).run_if(in_state(AppState::GameOver)));
+        app.add_systems(OnExit(AppState::GameOver), despawn_all::<leaderboard::GameOverView>);
+    }
+}
+
+/// The saved top scores, loaded once at startup and written back whenever a new entry is added.
+#[derive(Resource)]
+pub struct HighScoreTable {
+    // None when the platform has no data directory - scores then only last for this session
+    path: Option<PathBuf>,
+    pub entries: Vec<HighScoreEntry>,
+}
+
+impl HighScoreTable {
+    fn load() -> Self {
+        let path = default_high_score_path();
+        let entries = path.as_deref().map(load_table).unwrap_or_default();
+        Self { path, entries }
+    }
+
+    // Adds the entry if it makes the table, saving straight away. Returns its 0-based rank.
+    pub fn add(&mut self, entry: HighScoreEntry) -> Option<usize> {
+        let rank = store::insert_entry(&mut self.entries, entry)?;
+        if let Some(path) = &self.path {
+            if let Err(e) = store::save_table(path, &self.entries) {
+                error!("Failed to save high scores to {}: {}", path.display(), e);
+            }
+        }
+        Some(rank)
+    }
+}
+
+/// What the current (or just finished) run achieved - becomes a HighScoreEntry on game over.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct RunStats {
+    pub score: i32,
+    pub max_size: f32,
+    pub highest_tier: SnakeSpineNodeType,
+    pub duration_seconds: f32,
+    pub seed: u64,
+}
+
+impl Default for RunStats {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            max_size: 0.0,
+            highest_tier: SnakeSpineNodeType::Small,
+            duration_seconds: 0.0,
+            seed: 0,
+        }
+    }
+}
+
+impl RunStats {
+    pub fn to_entry(&self, initials: String) -> HighScoreEntry {
+        HighScoreEntry {
+            initials,
+            score: self.score,
+            max_size: self.max_size,
+            highest_tier: self.highest_tier,
+            duration_seconds: self.duration_seconds,
+            seed: self.seed,
+        }
+    }
+}
+
+// Folds one frame of play into the run's stats: keeps the best size/tier seen so far.
+pub fn record_run_frame(stats: &mut RunStats, score: i32, size: f32, tier: SnakeSpineNodeType, delta_seconds: f32) {
+    stats.score = score;
+    stats.max_size = stats.max_size.max(size);
+    if tier_rank(tier) > tier_rank(stats.highest_tier) {
+        stats.highest_tier = tier;
+    }
+    stats.duration_seconds += delta_seconds;
+}
+
+fn reset_run_stats(mut stats: ResMut<RunStats>, game_rng: Res<GameRng>) {
+    *stats = RunStats { seed: game_rng.seed, ..default() };
+}
+
+fn track_run_stats(
+    mut stats: ResMut<RunStats>,
+    snake_query: Query<&SnakeModel>,
+    score: Res<Score>,
+    clock: Res<GameClock>,
+) {
+    let Some(snake) = snake_query.iter().next() else { return; };
+    record_run_frame(&mut stats, score.points(), snake.size, snake.evolution_tier, clock.delta_seconds());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_stats_keep_the_best_size_and_tier() {
+        let mut stats = RunStats::default();
+        record_run_frame(&mut stats, 3, 12.0, SnakeSpineNodeType::Medium, 0.5);
+        record_run_frame(&mut stats, 3, 6.0, SnakeSpineNodeType::Small, 0.5);
+        assert_eq!(stats.max_size, 12.0);
+        assert_eq!(stats.highest_tier, SnakeSpineNodeType::Medium);
+        assert_eq!(stats.duration_seconds, 1.0);
+    }
+
+    #[test]
+    fn run_stats_follow_the_current_score() {
+        let mut stats = RunStats::default();
+        record_run_frame(&mut stats, 4, 5.0, SnakeSpineNodeType::Small, 0.1);
+        assert_eq!(stats.score, 4);
+    }
+}
@@ -0,0 +1,173 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+
+use crate::save_file::{backup_path, save_atomically};
+use crate::snake_model::SnakeSpineNodeType;
+
+// On-disk high score table. Saved as RON under the platform data directory, e.g.
+// ~/.local/share/Sylvester/high_scores.ron on Linux or %APPDATA%\Sylvester on Windows.
+//
+// Writes never touch the live file directly (see save_file.rs), and the previous good file
+// is kept as a .bak. If the live file is ever unreadable (crash mid-write, hand edits, a newer
+// version's format) loading falls back to the backup, and then to an empty table.
+
+// Bump when HighScoreFile/HighScoreEntry change shape, and migrate older versions in load_table.
+pub const HIGH_SCORE_FILE_VERSION: u32 = 1;
+// How many entries the table keeps (and the leaderboard shows).
+pub const MAX_HIGH_SCORES: usize = 10;
+
+const HIGH_SCORE_FILE_NAME: &str = "high_scores.ron";
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HighScoreEntry {
+    pub initials: String,
+    pub score: i32,
+    pub max_size: f32,
+    pub highest_tier: SnakeSpineNodeType,
+    pub duration_seconds: f32,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct HighScoreFile {
+    version: u32,
+    entries: Vec<HighScoreEntry>,
+}
+
+// Where the table lives, or None if the platform has no data directory (then scores just aren't kept).
+pub fn default_high_score_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("Sylvester").join(HIGH_SCORE_FILE_NAME))
+}
+
+fn parse_table(text: &str) -> Option<Vec<HighScoreEntry>> {
+    let file: HighScoreFile = ron::from_str(text).ok()?;
+    if file.version != HIGH_SCORE_FILE_VERSION {
+        return None;
+    }
+    Some(file.entries)
+}
+
+fn read_table(path: &Path) -> Option<Vec<HighScoreEntry>> {
+    let text = fs::read_to_string(path).ok()?;
+    parse_table(&text)
+}
+
+// Loads the table at `path`, falling back to its backup and then to an empty table.
+pub fn load_table(path: &Path) -> Vec<HighScoreEntry> {
+    read_table(path)
+        .or_else(|| read_table(&backup_path(path)))
+        .unwrap_or_default()
+}
+
+pub fn save_table(path: &Path, entries: &[HighScoreEntry]) -> std::io::Result<()> {
+    let file = HighScoreFile { version: HIGH_SCORE_FILE_VERSION, entries: entries.to_vec() };
+    let text = ron::ser::to_string_pretty(&file, ron::ser::PrettyConfig::default())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    // only a readable table is worth keeping as the backup
+    save_atomically(path, &text, read_table(path).is_some())
+}
+
+// True when `score` would make it onto a table that currently holds `entries`.
+pub fn qualifies(entries: &[HighScoreEntry], score: i32) -> bool {
+    entries.len() < MAX_HIGH_SCORES || entries.iter().any(|e| score > e.score)
+}
+
+// Inserts `entry` keeping the table sorted by score (highest first) and capped at
+// MAX_HIGH_SCORES. Ties go below existing entries - the earlier run keeps its place.
+// Returns the 0-based rank it landed at, or None if it didn't make the table.
+pub fn insert_entry(entries: &mut Vec<HighScoreEntry>, entry: HighScoreEntry) -> Option<usize> {
+    let rank = entries.iter().position(|e| entry.score > e.score).unwrap_or(entries.len());
+    if rank >= MAX_HIGH_SCORES {
+        return None;
+    }
+    entries.insert(rank, entry);
+    entries.truncate(MAX_HIGH_SCORES);
+    Some(rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(initials: &str, score: i32) -> HighScoreEntry {
+        HighScoreEntry {
+            initials: initials.to_string(),
+            score,
+            max_size: 12.0,
+            highest_tier: SnakeSpineNodeType::Medium,
+            duration_seconds: 95.5,
+            seed: 1234,
+        }
+    }
+
+    // fresh, empty directory per test so tests can run in parallel
+    fn test_path(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sylvester_high_score_{}_{}", test_name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join(HIGH_SCORE_FILE_NAME)
+    }
+
+    #[test]
+    fn insert_keeps_table_sorted_highest_first() {
+        let mut entries = vec![];
+        insert_entry(&mut entries, entry("AAA", 5));
+        insert_entry(&mut entries, entry("BBB", 9));
+        insert_entry(&mut entries, entry("CCC", 7));
+        let scores: Vec<i32> = entries.iter().map(|e| e.score).collect();
+        assert_eq!(scores, vec![9, 7, 5]);
+    }
+
+    #[test]
+    fn tie_goes_below_the_earlier_entry() {
+        let mut entries = vec![entry("AAA", 5)];
+        assert_eq!(insert_entry(&mut entries, entry("BBB", 5)), Some(1));
+        assert_eq!(entries[0].initials, "AAA");
+    }
+
+    #[test]
+    fn table_is_capped_and_low_scores_dont_qualify() {
+        let mut entries = vec![];
+        for i in 0..MAX_HIGH_SCORES as i32 {
+            insert_entry(&mut entries, entry("AAA", 10 + i));
+        }
+        assert!(!qualifies(&entries, 10));
+        assert_eq!(insert_entry(&mut entries, entry("BBB", 10)), None);
+        assert!(qualifies(&entries, 11));
+        assert_eq!(insert_entry(&mut entries, entry("CCC", 100)), Some(0));
+        assert_eq!(entries.len(), MAX_HIGH_SCORES);
+        assert_eq!(entries.last().unwrap().score, 11);
+    }
+
+    #[test]
+    fn empty_table_when_no_file_exists() {
+        let path = test_path("missing");
+        assert!(load_table(&path).is_empty());
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let path = test_path("round_trip");
+        let entries = vec![entry("ABC", 42), entry("XYZ", 7)];
+        save_table(&path, &entries).unwrap();
+        assert_eq!(load_table(&path), entries);
+    }
+
+    #[test]
+    fn corrupt_file_falls_back_to_backup() {
+        let path = test_path("corrupt");
+        let first = vec![entry("ABC", 42)];
+        save_table(&path, &first).unwrap();
+        save_table(&path, &[entry("ABC", 42), entry("DEF", 40)]).unwrap(); // backs up `first`
+        fs::write(&path, "(version: 1, entries: [(initials: \"GH").unwrap(); // torn write
+        assert_eq!(load_table(&path), first);
+    }
+
+    #[test]
+    fn unknown_version_is_not_trusted() {
+        let path = test_path("version");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "(version: 999, entries: [])").unwrap();
+        assert!(load_table(&path).is_empty());
+    }
+}
@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+
+use crate::creature_body_evolution::{SCALE_TRANSITION_DURATION, next_tier_threshold, tier_name, tier_progress};
+use crate::food::score::Score;
+use crate::game_flow::despawn_all;
+use crate::model::game_model::{AppState, TheGame};
+use crate::snake_model::SnakeModel;
+use crate::tunables::{TierTunables, Tunables};
+use crate::ui::theme::{FILL_COLOR, TRACK_COLOR, label};
+
+pub struct HudPlugin;
+
+impl Plugin for HudPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Playing), hud_start);
+        app.add_systems(Update, (hud_update, hud_progress_fill_update).run_if(in_state(AppState::Playing)));
+        app.add_systems(OnExit(AppState::Playing), despawn_all::<Hud>);
+    }
+}
+
+// Screen-space HUD pinned to the top-left corner, under TheGame.root_ui_node: score,
+// size, tier, progress toward the next tier and icons for whatever effects are active.
+
+const PROGRESS_BAR_WIDTH: f32 = 220.0;
+
+#[derive(Component)]
+struct Hud;
+
+#[derive(Component)]
+struct HudScoreText;
+
+#[derive(Component)]
+struct HudSizeText;
+
+#[derive(Component)]
+struct HudTierProgressFill;
+
+#[derive(Component)]
+struct HudTierProgressText;
+
+// Temporary effects the HUD shows an icon for while they last.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ActiveEffect {
+    // eating food in quick succession is multiplying points
+    Combo,
+    // node_radius is still easing into a newly reached tier
+    Evolving,
+}
+
+const ALL_EFFECTS: [ActiveEffect; 2] = [ActiveEffect::Combo, ActiveEffect::Evolving];
+
+#[derive(Component)]
+struct HudEffectIcon(ActiveEffect);
+
+pub(crate) fn effect_is_active(effect: ActiveEffect, score: &Score, snake: &SnakeModel) -> bool {
+    match effect {
+        ActiveEffect::Combo => score.combo().multiplier > 1,
+        ActiveEffect::Evolving => snake.evolution_transition_elapsed < SCALE_TRANSITION_DURATION,
+    }
+}
+
+fn effect_icon_style(effect: ActiveEffect) -> (&'static str, Color) {
+    match effect {
+        ActiveEffect::Combo => ("x", Color::srgb(0.95, 0.7, 0.2)),
+        ActiveEffect::Evolving => ("^", Color::srgb(0.4, 0.85, 0.5)),
+    }
+}
+
+// "Medium -> Big" while there's a next tier, just the tier name at the top.
+pub fn tier_progress_label(snake: &SnakeModel, tiers: &TierTunables) -> String {
+    match next_tier_threshold(snake.evolution_tier, tiers) {
+        Some((next_tier, _)) => format!("{} -> {}", tier_name(snake.evolution_tier), tier_name(next_tier)),
+        None => tier_name(snake.evolution_tier).to_string(),
+    }
+}
+
+fn hud_start(mut commands: Commands, the_game_query: Query<&TheGame>, asset_server: Res<AssetServer>) {
+    let Ok(the_game) = the_game_query.get_single() else { return; };
+    let hud = commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(12.0),
+                left: Val::Px(16.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            ..default()
+        },
+        Hud,
+    )).with_children(|parent| {
+        parent.spawn((label(&asset_server, "Score: 0", 36.0), HudScoreText));
+        parent.spawn((label(&asset_server, "", 22.0), HudSizeText));
+        parent.spawn((label(&asset_server, "", 18.0), HudTierProgressText));
+
+        // progress bar: a dim track with a fill whose width follows tier_progress
+        parent.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(PROGRESS_BAR_WIDTH),
+                height: Val::Px(10.0),
+                ..default()
+            },
+            background_color: TRACK_COLOR.into(),
+            border_radius: BorderRadius::MAX,
+            ..default()
+        }).with_children(|track| {
+            track.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: FILL_COLOR.into(),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                },
+                HudTierProgressFill,
+            ));
+        });
+
+        // one icon per effect, shown/hidden in hud_update
+        parent.spawn(NodeBundle {
+            style: Style {
+                column_gap: Val::Px(6.0),
+                margin: UiRect::top(Val::Px(4.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|icons| {
+            for effect in ALL_EFFECTS {
+                let (icon_label, color) = effect_icon_style(effect);
+                icons.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(26.0),
+                            height: Val::Px(26.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            display: Display::None,
+                            ..default()
+                        },
+                        background_color: color.into(),
+                        border_radius: BorderRadius::all(Val::Px(6.0)),
+                        ..default()
+                    },
+                    HudEffectIcon(effect),
+                )).with_children(|icon| {
+                    icon.spawn(label(&asset_server, icon_label, 18.0));
+                });
+            }
+        });
+    }).id();
+    commands.entity(the_game.root_ui_node).add_child(hud);
+}
+
+// The three HUD texts are all `&mut Text`, so each query has to rule the other two out.
+type ScoreTextFilter = (With<HudScoreText>, Without<HudSizeText>, Without<HudTierProgressText>);
+
+fn hud_update(
+    snake_query: Query<&SnakeModel>,
+    score: Res<Score>,
+    mut score_text_query: Query<&mut Text, ScoreTextFilter>,
+    mut size_text_query: Query<&mut Text, (With<HudSizeText>, Without<HudTierProgressText>)>,
+    mut tier_text_query: Query<&mut Text, With<HudTierProgressText>>,
+    mut icon_query: Query<(&mut Style, &HudEffectIcon)>,
+    tunables: Res<Tunables>,
+) {
+    let Some(snake) = snake_query.iter().next() else { return; };
+
+    for mut text in &mut score_text_query {
+        text.sections[0].value = score.text();
+    }
+    for mut text in &mut size_text_query {
+        text.sections[0].value = format!("Size {}   {}", snake.size as i32, tier_name(snake.evolution_tier));
+    }
+    for mut text in &mut tier_text_query {
+        text.sections[0].value = tier_progress_label(snake, &tunables.tiers);
+    }
+    for (mut style, icon) in &mut icon_query {
+        style.display = if effect_is_active(icon.0, &score, snake) { Display::Flex } else { Display::None };
+    }
+}
+
+fn hud_progress_fill_update(
+    snake_query: Query<&SnakeModel>,
+    tunables: Res<Tunables>,
+    mut fill_query: Query<&mut Style, With<HudTierProgressFill>>,
+) {
+    let Some(snake) = snake_query.iter().next() else { return; };
+    for mut style in &mut fill_query {
+        style.width = Val::Percent(tier_progress(snake.size, &tunables.tiers) * 100.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snake_model::{snake_model_new, SnakeSpineNodeType};
+
+    #[test]
+    fn tier_label_points_at_the_next_tier() {
+        let snake = snake_model_new(0);
+        assert_eq!(tier_progress_label(&snake, &TierTunables::default()), "Small -> Medium");
+    }
+
+    #[test]
+    fn tier_label_at_top_tier_is_just_the_name() {
+        let mut snake = snake_model_new(0);
+        snake.evolution_tier = SnakeSpineNodeType::Big;
+        assert_eq!(tier_progress_label(&snake, &TierTunables::default()), "Big");
+    }
+
+    #[test]
+    fn evolving_icon_only_during_a_transition() {
+        let mut snake = snake_model_new(0);
+        let score = Score::default();
+        assert!(!effect_is_active(ActiveEffect::Evolving, &score, &snake));
+        snake.evolution_transition_elapsed = 0.0;
+        assert!(effect_is_active(ActiveEffect::Evolving, &score, &snake));
+    }
+
+    #[test]
+    fn no_combo_icon_without_a_streak() {
+        let snake = snake_model_new(0);
+        assert!(!effect_is_active(ActiveEffect::Combo, &Score::default(), &snake));
+    }
+}
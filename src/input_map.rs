@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::grid::DebugDrawCategory;
+use crate::settings::Settings;
+
+pub struct InputMapPlugin;
+
+impl Plugin for InputMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<InputMap>();
+        app.add_systems(Update, apply_control_scheme.run_if(resource_exists_and_changed::<Settings>));
+    }
+}
+
+// Things the player can do, independent of which key does them. Systems ask the
+// InputMap about an action instead of checking a KeyCode directly, so keys can be
+// rebound in one place.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum InputAction {
+    MoveForward,
+    MoveBackward,
+    TurnLeft,
+    TurnRight,
+    Pause,
+    ToggleDiagnostics,
+    ToggleInspector,
+    ToggleDebugDraw(DebugDrawCategory),
+    SlowDownTime,
+    SpeedUpTime,
+    ToggleTimePause,
+    StepFrame,
+    ExportSvg,
+    SaveTraceFixture,
+}
+
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct InputMap {
+    // an action can have several keys; any of them triggers it
+    bindings: Vec<(InputAction, KeyCode)>,
+}
+
+// Preset key layouts the player picks between in the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlScheme {
+    Arrows,
+    Wasd,
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        Self::for_scheme(ControlScheme::Arrows)
+    }
+}
+
+impl InputMap {
+    pub fn for_scheme(scheme: ControlScheme) -> Self {
+        let (forward, backward, left, right) = match scheme {
+            ControlScheme::Arrows => (KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight),
+            ControlScheme::Wasd => (KeyCode::KeyW, KeyCode::KeyS, KeyCode::KeyA, KeyCode::KeyD),
+        };
+        Self {
+            bindings: vec![
+                (InputAction::MoveForward, forward),
+                (InputAction::MoveBackward, backward),
+                (InputAction::TurnLeft, left),
+                (InputAction::TurnRight, right),
+                (InputAction::Pause, KeyCode::Escape),
+                (InputAction::ToggleDiagnostics, KeyCode::Digit1),
+                (InputAction::ToggleInspector, KeyCode::Digit2),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::Grid), KeyCode::Digit3),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::TracePolyline), KeyCode::Digit4),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::SegmentCircles), KeyCode::Digit5),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::HeadHitbox), KeyCode::Digit6),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::FoodHitboxes), KeyCode::Digit7),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::Bound), KeyCode::Digit8),
+                (InputAction::ToggleDebugDraw(DebugDrawCategory::SplineTangents), KeyCode::Digit9),
+                (InputAction::SlowDownTime, KeyCode::BracketLeft),
+                (InputAction::SpeedUpTime, KeyCode::BracketRight),
+                (InputAction::ToggleTimePause, KeyCode::KeyP),
+                (InputAction::StepFrame, KeyCode::Period),
+                (InputAction::ExportSvg, KeyCode::F2),
+                (InputAction::SaveTraceFixture, KeyCode::F9),
+            ],
+        }
+    }
+
+    pub fn keys(&self, action: InputAction) -> impl Iterator<Item = KeyCode> + '_ {
+        self.bindings.iter().filter(move |(a, _)| *a == action).map(|(_, key)| *key)
+    }
+
+    // True while any key bound to `action` is held down.
+    pub fn pressed(&self, action: InputAction, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        keyboard_input.any_pressed(self.keys(action))
+    }
+
+    // True on the frame any key bound to `action` goes down.
+    pub fn just_pressed(&self, action: InputAction, keyboard_input: &ButtonInput<KeyCode>) -> bool {
+        keyboard_input.any_just_pressed(self.keys(action))
+    }
+}
+
+fn apply_control_scheme(settings: Res<Settings>, mut input_map: ResMut<InputMap>) {
+    let scheme_map = InputMap::for_scheme(settings.control_scheme);
+    if *input_map != scheme_map {
+        *input_map = scheme_map;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_bindings_match_the_original_controls() {
+        let map = InputMap::default();
+        assert_eq!(map.keys(InputAction::MoveForward).collect::<Vec<_>>(), vec![KeyCode::ArrowUp]);
+        assert_eq!(map.keys(InputAction::Pause).collect::<Vec<_>>(), vec![KeyCode::Escape]);
+    }
+
+    #[test]
+    fn action_follows_its_bound_key() {
+        let map = InputMap::default();
+        let mut keyboard_input = ButtonInput::<KeyCode>::default();
+        keyboard_input.press(KeyCode::ArrowLeft);
+        assert!(map.pressed(InputAction::TurnLeft, &keyboard_input));
+        assert!(map.just_pressed(InputAction::TurnLeft, &keyboard_input));
+        assert!(!map.pressed(InputAction::TurnRight, &keyboard_input));
+    }
+
+    #[test]
+    fn wasd_scheme_moves_with_letters_and_keeps_escape() {
+        let map = InputMap::for_scheme(ControlScheme::Wasd);
+        assert_eq!(map.keys(InputAction::TurnLeft).collect::<Vec<_>>(), vec![KeyCode::KeyA]);
+        assert_eq!(map.keys(InputAction::Pause).collect::<Vec<_>>(), vec![KeyCode::Escape]);
+    }
+}
@@ -0,0 +1,103 @@
+//! Sylvester: a creature that swims after food and evolves as it grows.
+//!
+//! The game is [`SylvesterPlugins`] on top of bevy's `DefaultPlugins` (see main.rs). Tools and
+//! other scenes can take just the creature with [`CreaturePlugins`], or use the pure modules
+//! (trace_position_calculator, snake_model, body_profile, swimming, creature_body_evolution)
+//! without any app at all.
+
+use bevy::app::{PluginGroup, PluginGroupBuilder};
+
+// creature model and the math that lays its body out - no systems, usable anywhere
+pub mod snake_model;
+pub mod trace_position_calculator;
+pub mod body_profile;
+pub mod swimming;
+pub mod creature_body_evolution;
+pub mod appendage;
+pub mod foo;
+
+// gameplay plugins
+pub mod model;
+pub mod snake_extension;
+pub mod food;
+pub mod self_collision;
+pub mod game_rng;
+pub mod gameplay_events;
+pub mod tunables;
+pub mod game_clock;
+pub mod game_flow;
+pub mod input_map;
+pub mod grid;
+pub mod environment;
+pub mod high_score;
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod particles;
+
+// how settings and high scores are written to disk
+mod save_file;
+
+// menus and overlays
+pub mod start;
+pub mod hud;
+pub mod pause_menu;
+pub mod ui;
+pub mod settings;
+#[cfg(feature = "debug")]
+pub mod debug_inspector;
+pub mod svg_export;
+pub mod trace_fixture;
+
+// older sprite-sheet experiment, not part of SylvesterPlugins
+pub mod sprite;
+
+/// The whole game: menus, runs, scoring, settings, sound and the debug tools.
+/// Needs `DefaultPlugins` (and a camera) to be added alongside it. Sound and the debug
+/// tools are left out when the "audio" / "debug" cargo features are off.
+pub struct SylvesterPlugins;
+
+impl PluginGroup for SylvesterPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>()
+            .add(game_flow::GameFlowPlugin)
+            .add(tunables::TunablesPlugin)
+            .add(game_clock::GameClockPlugin)
+            .add(start::StartPlugin)
+            .add(environment::EnvironmentPlugin)
+            .add(grid::VisualDiagnosticPlugin)
+            .add(snake_extension::SnakePlugin)
+            .add(food::FoodPlugin)
+            .add(self_collision::SelfCollisionPlugin)
+            .add(game_rng::GameRngPlugin)
+            .add(high_score::HighScorePlugin)
+            .add(hud::HudPlugin)
+            .add(input_map::InputMapPlugin)
+            .add(pause_menu::PauseMenuPlugin)
+            .add(ui::UiWidgetsPlugin)
+            .add(settings::SettingsPlugin)
+            .add(gameplay_events::GameplayEventsPlugin)
+            .add(particles::ParticlesPlugin);
+        #[cfg(feature = "audio")]
+        let group = group.add(audio::GameAudioPlugin);
+        #[cfg(feature = "debug")]
+        let group = group.add(debug_inspector::DebugInspectorPlugin).add(svg_export::SvgExportPlugin).add(trace_fixture::TraceFixturePlugin);
+        group
+    }
+}
+
+/// Just the player-controlled creature, for embedding it in another scene. The host app
+/// provides the states: `AppState` plus the `PlayState` sub-state, entering
+/// `AppState::Playing` to spawn the creature (see examples/creature_scene.rs).
+pub struct CreaturePlugins;
+
+impl PluginGroup for CreaturePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(gameplay_events::GameplayEventsPlugin)
+            .add(tunables::TunablesPlugin)
+            .add(game_clock::GameClockPlugin)
+            .add(input_map::InputMapPlugin)
+            .add(grid::VisualDiagnosticPlugin)
+            .add(snake_extension::SnakePlugin)
+    }
+}
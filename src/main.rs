@@ -1,37 +1,17 @@
-mod model;
-
-mod start;
-mod environment;
-mod grid;
-mod sprite;
-mod snake_extension;
-mod snake_model;
-mod food;
-mod foo;
-mod creature_body_evolution;
-mod trace_position_calculator;
 use bevy::{
     prelude::*,
     sprite::Wireframe2dPlugin,
 };
+use sylvester::SylvesterPlugins;
 
 fn main() {
     App::new()
         .add_plugins((DefaultPlugins, Wireframe2dPlugin))
-        .init_state::<crate::model::game_model::AppState>()
-
-        .add_plugins(crate::start::StartPlugin)
-        .add_plugins(crate::environment::EnvironmentPlugin)
-        .add_plugins(crate::grid::VisualDiagnosticPlugin)
-        .add_plugins(crate::snake_extension::SnakePlugin)
-        .add_plugins(crate::food::FoodPlugin)
-        //.add_plugins(crate::sprite::SpritePlugin)
-
+        .add_plugins(SylvesterPlugins)
         .add_systems(Startup, setup)
-
         .run();
 }
 
 fn setup(mut commands: Commands) {
     commands.spawn(Camera2dBundle::default());
-}
\ No newline at end of file
+}
@@ -1,6 +1,8 @@
 use bevy::prelude::Entity;
 use bevy::prelude::Component;
 use bevy::prelude::States;
+use bevy::prelude::SubStates;
+use bevy::prelude::StateSet;
 
 /// Global game component.
 #[derive(Component)]
@@ -15,9 +17,25 @@ pub struct TheGame {
 
 /// Drives whether gameplay systems (snake/food) are allowed to run.
 /// Starts on the menu; the Start button moves this to Playing.
+/// Eating poison at minimum size moves it to GameOver, where the run's high score is recorded.
+/// Everything a run spawns is despawned on OnExit(Playing), so each run starts clean.
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum AppState {
     #[default]
     MainMenu,
     Playing,
+    GameOver,
+    /// One-frame hop used to restart: Bevy skips OnExit/OnEnter for a Playing -> Playing
+    /// transition, so restarting goes Playing -> Restarting -> Playing (see game_flow.rs).
+    Restarting,
+}
+
+/// Only exists while AppState is Playing. Gameplay systems run in Running and freeze in
+/// Paused; since pausing doesn't leave Playing, it doesn't trigger the OnExit(Playing) teardown.
+#[derive(SubStates, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[source(AppState = AppState::Playing)]
+pub enum PlayState {
+    #[default]
+    Running,
+    Paused,
 }
\ No newline at end of file
@@ -0,0 +1,288 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f32::consts;
+
+use crate::creature_body_evolution::{PARKED_SEGMENT_POSITION, tier_rank};
+use crate::food::food_sprite_color;
+use crate::gameplay_events::{FoodEaten, FoodKind, TierChanged};
+use crate::model::game_model::{AppState, PlayState};
+use crate::settings::Settings;
+use crate::snake_model::SnakeModel;
+use crate::game_clock::GameClock;
+
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ParticleRng(StdRng::from_rng(rand::thread_rng()).unwrap()));
+        app.add_systems(Startup, spawn_particle_pool);
+        app.add_systems(Update, (
+            (emit_food_particles, emit_tier_ring),
+            particle_update,
+        ).chain().run_if(in_state(PlayState::Running)));
+        app.add_systems(OnExit(AppState::Playing), park_all_particles);
+    }
+}
+
+// Short-lived sprites thrown off by gameplay events: motes when food is eaten, a sickly
+// puff on poison, a ring of light from the head when the tier changes. Like the body
+// segments (spine_from_size), the sprites are spawned once up front and reused; a parked
+// particle sits hidden at PARKED_SEGMENT_POSITION until the next burst picks it up.
+
+const PARTICLE_POOL_SIZE: usize = 256;
+// Above food and the body, so bursts aren't hidden under the creature.
+const PARTICLE_Z: f32 = 5.0;
+
+// Its own randomness, so cosmetic bursts don't shift GameRng's sequence and break replays.
+#[derive(Resource)]
+struct ParticleRng(StdRng);
+
+#[derive(Resource)]
+struct ParticlePool {
+    particles: Vec<Entity>,
+    // next particle to hand out; wrapping around reuses the oldest ones first
+    next: usize,
+}
+
+#[derive(Component, Default)]
+struct Particle {
+    velocity: Vec2,
+    // fraction of velocity lost per second
+    drag: f32,
+    age: f32,
+    // 0 = parked
+    lifetime: f32,
+    color: ColorCurve,
+    size: SizeCurve,
+}
+
+// Start and end of a value over a particle's life, eased linearly.
+#[derive(Clone, Copy, Default)]
+pub struct ColorCurve {
+    pub start: Color,
+    pub end: Color,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct SizeCurve {
+    pub start: f32,
+    pub end: f32,
+}
+
+// One kind of burst; emit takes `count` particles from the pool and launches them.
+struct BurstSettings {
+    count: usize,
+    speed: (f32, f32),
+    lifetime: (f32, f32),
+    drag: f32,
+    color: ColorCurve,
+    size: SizeCurve,
+}
+
+impl ColorCurve {
+    pub fn at(&self, t: f32) -> Color {
+        let start = self.start.to_linear();
+        let end = self.end.to_linear();
+        Color::from(start.mix(&end, t.clamp(0.0, 1.0)))
+    }
+}
+
+impl SizeCurve {
+    pub fn at(&self, t: f32) -> f32 {
+        self.start + (self.end - self.start) * t.clamp(0.0, 1.0)
+    }
+}
+
+fn food_motes(color: Color) -> BurstSettings {
+    BurstSettings {
+        count: 12,
+        speed: (60.0, 160.0),
+        lifetime: (0.3, 0.6),
+        drag: 3.0,
+        color: ColorCurve { start: color, end: color.with_alpha(0.0) },
+        size: SizeCurve { start: 6.0, end: 2.0 },
+    }
+}
+
+// Slow, heavy and growing, so it reads as a cloud rather than a sparkle.
+fn poison_puff(color: Color) -> BurstSettings {
+    BurstSettings {
+        count: 16,
+        speed: (20.0, 70.0),
+        lifetime: (0.6, 1.0),
+        drag: 1.5,
+        color: ColorCurve { start: color.with_alpha(0.8), end: color.darker(0.3).with_alpha(0.0) },
+        size: SizeCurve { start: 8.0, end: 16.0 },
+    }
+}
+
+fn tier_ring(growing: bool) -> BurstSettings {
+    let color = if growing { Color::srgb(1.0, 0.95, 0.6) } else { Color::srgb(0.6, 0.7, 1.0) };
+    BurstSettings {
+        count: 36,
+        speed: (220.0, 220.0),
+        lifetime: (0.5, 0.5),
+        drag: 1.0,
+        color: ColorCurve { start: color, end: color.with_alpha(0.0) },
+        size: SizeCurve { start: 7.0, end: 3.0 },
+    }
+}
+
+// Velocity after `delta_seconds` of drag; never reverses direction however big the step.
+pub fn apply_drag(velocity: Vec2, drag: f32, delta_seconds: f32) -> Vec2 {
+    velocity * (1.0 - drag * delta_seconds).max(0.0)
+}
+
+// `count` directions evenly spaced around the circle, for the tier ring.
+pub fn ring_directions(count: usize) -> Vec<Vec2> {
+    (0..count)
+        .map(|i| Vec2::from_angle(i as f32 / count as f32 * consts::TAU))
+        .collect()
+}
+
+fn spawn_particle_pool(mut commands: Commands) {
+    let particles = (0..PARTICLE_POOL_SIZE)
+        .map(|_| {
+            commands.spawn((
+                SpriteBundle {
+                    transform: Transform::from_translation(PARKED_SEGMENT_POSITION),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                Particle::default(),
+            )).id()
+        })
+        .collect();
+    commands.insert_resource(ParticlePool { particles, next: 0 });
+}
+
+fn emit(
+    pool: &mut ParticlePool,
+    particle_query: &mut Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    rng: &mut impl Rng,
+    pos: Vec2,
+    directions: impl Iterator<Item = Vec2>,
+    burst: &BurstSettings,
+) {
+    for direction in directions.take(burst.count) {
+        let entity = pool.particles[pool.next];
+        pool.next = (pool.next + 1) % pool.particles.len();
+        let Ok((mut particle, mut transform, mut sprite, mut visibility)) = particle_query.get_mut(entity) else { continue; };
+        *particle = Particle {
+            velocity: direction * rng.gen_range(burst.speed.0..=burst.speed.1),
+            drag: burst.drag,
+            age: 0.0,
+            lifetime: rng.gen_range(burst.lifetime.0..=burst.lifetime.1),
+            color: burst.color,
+            size: burst.size,
+        };
+        transform.translation = pos.extend(PARTICLE_Z);
+        sprite.color = burst.color.start;
+        sprite.custom_size = Some(Vec2::splat(burst.size.start));
+        *visibility = Visibility::Visible;
+    }
+}
+
+fn emit_food_particles(
+    mut pool: ResMut<ParticlePool>,
+    mut particle_query: Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    mut particle_rng: ResMut<ParticleRng>,
+    mut food_eaten_events: EventReader<FoodEaten>,
+    settings: Res<Settings>,
+) {
+    let rng = &mut particle_rng.0;
+    for event in food_eaten_events.read() {
+        let burst = match event.kind {
+            FoodKind::Normal => food_motes(food_sprite_color(false, settings.colorblind_palette)),
+            FoodKind::Poison => poison_puff(food_sprite_color(true, settings.colorblind_palette)),
+        };
+        let directions: Vec<Vec2> = (0..burst.count).map(|_| Vec2::from_angle(rng.gen_range(0.0..consts::TAU))).collect();
+        emit(&mut pool, &mut particle_query, rng, event.pos, directions.into_iter(), &burst);
+    }
+}
+
+fn emit_tier_ring(
+    mut pool: ResMut<ParticlePool>,
+    mut particle_query: Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    mut particle_rng: ResMut<ParticleRng>,
+    mut tier_changed_events: EventReader<TierChanged>,
+    snake_query: Query<&SnakeModel>,
+) {
+    let Some(snake) = snake_query.iter().next() else { return; };
+    for event in tier_changed_events.read() {
+        let burst = tier_ring(tier_rank(event.to) > tier_rank(event.from));
+        emit(&mut pool, &mut particle_query, &mut particle_rng.0, snake.head_pos, ring_directions(burst.count).into_iter(), &burst);
+    }
+}
+
+fn particle_update(
+    mut particle_query: Query<(&mut Particle, &mut Transform, &mut Sprite, &mut Visibility)>,
+    clock: Res<GameClock>,
+) {
+    let delta_seconds = clock.delta_seconds();
+    for (mut particle, mut transform, mut sprite, mut visibility) in &mut particle_query {
+        if particle.lifetime <= 0.0 {
+            continue;
+        }
+        particle.age += delta_seconds;
+        if particle.age >= particle.lifetime {
+            park(&mut particle, &mut transform, &mut visibility);
+            continue;
+        }
+        particle.velocity = apply_drag(particle.velocity, particle.drag, delta_seconds);
+        transform.translation += (particle.velocity * delta_seconds).extend(0.0);
+        let t = particle.age / particle.lifetime;
+        sprite.color = particle.color.at(t);
+        sprite.custom_size = Some(Vec2::splat(particle.size.at(t)));
+    }
+}
+
+fn park(particle: &mut Particle, transform: &mut Transform, visibility: &mut Visibility) {
+    particle.lifetime = 0.0;
+    transform.translation = PARKED_SEGMENT_POSITION;
+    *visibility = Visibility::Hidden;
+}
+
+// Bursts left over from a run shouldn't hang in the air over the menus.
+fn park_all_particles(mut particle_query: Query<(&mut Particle, &mut Transform, &mut Visibility)>) {
+    for (mut particle, mut transform, mut visibility) in &mut particle_query {
+        park(&mut particle, &mut transform, &mut visibility);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_curve_runs_from_start_to_end() {
+        let curve = ColorCurve { start: Color::WHITE, end: Color::WHITE.with_alpha(0.0) };
+        assert_eq!(curve.at(0.0).alpha(), 1.0);
+        assert!((curve.at(0.5).alpha() - 0.5).abs() < 1e-5);
+        assert_eq!(curve.at(2.0).alpha(), 0.0);
+    }
+
+    #[test]
+    fn size_curve_interpolates_and_clamps() {
+        let curve = SizeCurve { start: 8.0, end: 16.0 };
+        assert_eq!(curve.at(0.5), 12.0);
+        assert_eq!(curve.at(-1.0), 8.0);
+        assert_eq!(curve.at(1.5), 16.0);
+    }
+
+    #[test]
+    fn drag_slows_but_never_reverses() {
+        let velocity = Vec2::new(100.0, 0.0);
+        assert_eq!(apply_drag(velocity, 2.0, 0.25), Vec2::new(50.0, 0.0));
+        assert_eq!(apply_drag(velocity, 2.0, 10.0), Vec2::ZERO);
+    }
+
+    #[test]
+    fn ring_directions_are_evenly_spread_unit_vectors() {
+        let directions = ring_directions(4);
+        assert_eq!(directions.len(), 4);
+        assert!(directions.iter().all(|d| (d.length() - 1.0).abs() < 1e-5));
+        assert!(directions.iter().sum::<Vec2>().length() < 1e-5);
+    }
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+
+use crate::game_flow::despawn_all;
+use crate::model::game_model::{PlayState, TheGame};
+use crate::ui::button::{MenuAction, spawn_menu_button};
+use crate::ui::theme::{TITLE_FONT_SIZE, label};
+
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(PlayState::Paused), spawn_pause_overlay);
+        app.add_systems(OnExit(PlayState::Paused), despawn_all::<PauseOverlay>);
+    }
+}
+
+// Dims the whole screen while paused. Gameplay systems only run in PlayState::Running,
+// so the run stays frozen underneath until Resume (or Escape again).
+const OVERLAY_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+
+#[derive(Component)]
+struct PauseOverlay;
+
+fn spawn_pause_overlay(mut commands: Commands, the_game_query: Query<&TheGame>, asset_server: Res<AssetServer>) {
+    let Ok(the_game) = the_game_query.get_single() else { return; };
+    let overlay = commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            background_color: OVERLAY_COLOR.into(),
+            ..default()
+        },
+        PauseOverlay,
+    )).with_children(|parent| {
+        parent.spawn(label(&asset_server, "Paused", TITLE_FONT_SIZE));
+        spawn_menu_button(parent, &asset_server, "Resume", MenuAction::Resume);
+        spawn_menu_button(parent, &asset_server, "Restart", MenuAction::Restart);
+        spawn_menu_button(parent, &asset_server, "Settings", MenuAction::Settings);
+        spawn_menu_button(parent, &asset_server, "Quit to Menu", MenuAction::QuitToMenu);
+    }).id();
+    commands.entity(the_game.root_ui_node).add_child(overlay);
+}
@@ -0,0 +1,66 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+// How the stores that outlive a run (settings, high scores) write their files. The new
+// contents go to a .tmp file next to the real one, which is then renamed over it (atomic on
+// the same filesystem), so a crash mid-write can't leave a torn file behind.
+
+// `path` with `suffix` tacked on the end, e.g. high_scores.ron -> high_scores.ron.bak.
+pub(crate) fn with_extension_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+pub(crate) fn backup_path(path: &Path) -> PathBuf {
+    with_extension_suffix(path, ".bak")
+}
+
+// Replaces the file at `path` with `text`, creating its directory if needed. With
+// `back_up_current`, the file being replaced is first copied to backup_path(path) - callers
+// decide whether the current one is worth keeping.
+pub(crate) fn save_atomically(path: &Path, text: &str, back_up_current: bool) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp_path = with_extension_suffix(path, ".tmp");
+    {
+        let mut tmp = fs::File::create(&tmp_path)?;
+        tmp.write_all(text.as_bytes())?;
+        tmp.sync_all()?;
+    }
+    if back_up_current {
+        fs::copy(path, backup_path(path))?;
+    }
+    fs::rename(&tmp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_path(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sylvester_save_file_{}_{}", test_name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("data.ron")
+    }
+
+    #[test]
+    fn saving_replaces_the_file_and_leaves_no_tmp() {
+        let path = test_path("replace");
+        save_atomically(&path, "first", false).unwrap();
+        save_atomically(&path, "second", false).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert!(!with_extension_suffix(&path, ".tmp").exists());
+        assert!(!backup_path(&path).exists());
+    }
+
+    #[test]
+    fn the_replaced_file_can_be_kept_as_a_backup() {
+        let path = test_path("backup");
+        save_atomically(&path, "first", false).unwrap();
+        save_atomically(&path, "second", true).unwrap();
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), "first");
+    }
+}
@@ -0,0 +1,223 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::gameplay_events::{CreatureSevered, SizeChanged};
+use crate::model::game_model::PlayState;
+use crate::settings::Settings;
+use crate::snake_extension::snake_update;
+use crate::snake_model::SnakeModel;
+
+pub struct SelfCollisionPlugin;
+
+impl Plugin for SelfCollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, self_collision_update.after(snake_update).run_if(in_state(PlayState::Running)));
+    }
+}
+
+// What happens when the head runs into the creature's own body - picked as part of the
+// game mode on the settings screen (Settings.self_collision).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SelfCollision {
+    // the head swims straight through, like it always has
+    Ignore,
+    // the head is pushed back out and glances off
+    Bounce,
+    // biting the body severs it there; the lost segments are left behind as food
+    Ouroboros,
+}
+
+// Segments this close to the head always touch their neighbours, so they can't be bitten.
+const FIRST_BITEABLE_SEGMENT: usize = 3;
+// How far the head circle has to sink into a segment's circle (as a fraction of the two
+// radii together) before it counts, so grazing past doesn't.
+const BITE_REACH: f32 = 0.8;
+
+// The body segment the head is overlapping, using the positions draw_nodes laid the body
+// out at this frame (SnakeModel.segment_positions). The closest one wins.
+pub fn bitten_segment(snake: &SnakeModel) -> Option<usize> {
+    let head_radius = snake.body.first()?.radius;
+    snake.segment_positions.iter().enumerate()
+        .skip(FIRST_BITEABLE_SEGMENT)
+        .filter_map(|(i, pos)| {
+            let reach = (head_radius + snake.body.get(i)?.radius) * BITE_REACH;
+            let distance = pos.distance(snake.head_pos);
+            (distance < reach).then_some((i, distance))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(i, _)| i)
+}
+
+// Pushes the head out of `segment` and mirrors its heading off the contact, if it was
+// heading into it.
+pub fn bounce_off(snake: &mut SnakeModel, segment: usize) {
+    let (Some(&segment_pos), Some(head), Some(body)) = (snake.segment_positions.get(segment), snake.body.first(), snake.body.get(segment)) else { return; };
+    let normal = (snake.head_pos - segment_pos).try_normalize().unwrap_or(-Vec2::from_angle(snake.head_direction_angle));
+    snake.head_pos = segment_pos + normal * (head.radius + body.radius);
+    let direction = Vec2::from_angle(snake.head_direction_angle);
+    let into = direction.dot(normal);
+    if into < 0.0 {
+        let bounced = direction - 2.0 * into * normal;
+        snake.head_direction_angle = bounced.y.atan2(bounced.x);
+    }
+}
+
+// Cuts the body off just before `segment`, which becomes the first lost segment. Returns
+// where the lost segments were.
+pub fn sever_at(snake: &mut SnakeModel, segment: usize) -> Vec<Vec2> {
+    let severed = snake.segment_positions.split_off(segment.min(snake.segment_positions.len()));
+    snake.size = (segment as f32 - 1.0).max(1.0);
+    severed
+}
+
+fn self_collision_update(
+    settings: Res<Settings>,
+    mut snake_query: Query<(Entity, &mut SnakeModel)>,
+    mut severed_events: EventWriter<CreatureSevered>,
+    mut size_changed_events: EventWriter<SizeChanged>,
+) {
+    if settings.self_collision == SelfCollision::Ignore {
+        return;
+    }
+    for (creature, mut snake) in &mut snake_query {
+        let Some(segment) = bitten_segment(&snake) else { continue; };
+        match settings.self_collision {
+            SelfCollision::Ignore => {}
+            SelfCollision::Bounce => bounce_off(&mut snake, segment),
+            SelfCollision::Ouroboros => {
+                let from = snake.size;
+                let positions = sever_at(&mut snake, segment);
+                severed_events.send(CreatureSevered { creature, segment, positions });
+                if snake.size != from {
+                    size_changed_events.send(SizeChanged { creature, from, to: snake.size });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use crate::food::{Food, FoodPlugin};
+    use crate::game_clock::GameClock;
+    use crate::game_rng::GameRngPlugin;
+    use crate::gameplay_events::{FoodEaten, GameplayEventsPlugin};
+    use crate::grid::DebugDraw;
+    use crate::model::game_model::AppState;
+    use crate::snake_model::{SnakeSpineNode, snake_model_new};
+    use crate::tunables::Tunables;
+
+    // A creature with `positions` as its laid-out body, every segment of radius 10.
+    fn laid_out(positions: &[Vec2]) -> SnakeModel {
+        let mut snake = snake_model_new(0);
+        snake.head_pos = positions[0];
+        snake.size = (positions.len() - 1) as f32;
+        snake.body = positions.iter().enumerate()
+            .map(|(i, _)| SnakeSpineNode { distance_from_head: i as f32 * 20.0, radius: 10.0, node_type: Entity::PLACEHOLDER })
+            .collect();
+        snake.segment_positions = positions.to_vec();
+        snake
+    }
+
+    // head at the origin heading right, body curled round so segment 5 sits just in front
+    fn curled() -> SnakeModel {
+        let mut snake = laid_out(&[
+            Vec2::new(0.0, 0.0),
+            Vec2::new(-20.0, 0.0),
+            Vec2::new(-30.0, 18.0),
+            Vec2::new(-15.0, 32.0),
+            Vec2::new(5.0, 30.0),
+            Vec2::new(12.0, 5.0),
+            Vec2::new(30.0, -10.0),
+            Vec2::new(50.0, -15.0),
+        ]);
+        snake.head_direction_angle = 0.0;
+        snake
+    }
+
+    #[test]
+    fn head_bites_the_segment_it_overlaps() {
+        assert_eq!(bitten_segment(&curled()), Some(5));
+    }
+
+    #[test]
+    fn neighbours_of_the_head_are_never_bitten() {
+        let snake = laid_out(&[Vec2::ZERO, Vec2::new(-5.0, 0.0), Vec2::new(-10.0, 0.0), Vec2::new(-40.0, 0.0)]);
+        assert_eq!(bitten_segment(&snake), None);
+    }
+
+    #[test]
+    fn bouncing_pushes_the_head_out_and_turns_it_away() {
+        let mut snake = curled();
+        bounce_off(&mut snake, 5);
+        let segment_pos = snake.segment_positions[5];
+        assert!(snake.head_pos.distance(segment_pos) >= 20.0 - 0.001);
+        assert!(Vec2::from_angle(snake.head_direction_angle).dot(snake.head_pos - segment_pos) >= 0.0);
+    }
+
+    #[derive(Resource, Default)]
+    struct FoodEatenCount(usize);
+
+    fn count_food_eaten(mut food_eaten_events: EventReader<FoodEaten>, mut count: ResMut<FoodEatenCount>) {
+        count.0 += food_eaten_events.read().count();
+    }
+
+    // The real food and self-collision plugins, headless, with `snake` as the creature and
+    // no food of its own, so the only food around is whatever a bite leaves behind.
+    fn ouroboros_app(snake: SnakeModel) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, AssetPlugin::default()));
+        app.init_asset::<Image>();
+        app.init_asset::<Shader>();
+        app.add_plugins(bevy::gizmos::GizmoPlugin);
+        app.insert_resource(Settings { self_collision: SelfCollision::Ouroboros, ..Settings::default() });
+        let mut tunables = Tunables::default();
+        tunables.food.base_food_count = 0;
+        app.insert_resource(tunables);
+        let mut clock = GameClock::default();
+        clock.tick(1.0 / 60.0);
+        app.insert_resource(clock);
+        app.init_resource::<DebugDraw>();
+        app.init_resource::<FoodEatenCount>();
+        app.init_state::<AppState>();
+        app.add_sub_state::<PlayState>();
+        app.add_plugins((GameplayEventsPlugin, GameRngPlugin, FoodPlugin, SelfCollisionPlugin));
+        app.add_systems(PostUpdate, count_food_eaten);
+        app.world_mut().spawn(snake);
+        app.world_mut().resource_mut::<NextState<AppState>>().set(AppState::Playing);
+        app
+    }
+
+    #[test]
+    fn severed_pieces_are_not_eaten_straight_back() {
+        let mut app = ouroboros_app(curled());
+        // bite, then half a second of sitting right on top of the pieces
+        for _ in 0..30 {
+            app.update();
+        }
+        let world = app.world_mut();
+        assert_eq!(world.query::<&Food>().iter(world).count(), 3);
+        assert_eq!(world.query::<&SnakeModel>().single(world).size, 4.0);
+        assert_eq!(world.resource::<FoodEatenCount>().0, 0);
+
+        // once the pause is over they're ordinary food
+        for _ in 0..40 {
+            app.update();
+        }
+        let world = app.world_mut();
+        let piece = world.query::<&Food>().iter(world).next().unwrap().pos();
+        world.query::<&mut SnakeModel>().single_mut(world).head_pos = piece;
+        app.update();
+        assert!(app.world().resource::<FoodEatenCount>().0 > 0);
+    }
+
+    #[test]
+    fn ouroboros_keeps_the_body_up_to_the_bite() {
+        let mut snake = curled();
+        let severed = sever_at(&mut snake, 5);
+        assert_eq!(snake.size, 4.0);
+        assert_eq!(severed, vec![Vec2::new(12.0, 5.0), Vec2::new(30.0, -10.0), Vec2::new(50.0, -15.0)]);
+    }
+}
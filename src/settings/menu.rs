@@ -0,0 +1,281 @@
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+
+use super::{Settings, SettingsPath, WindowModeSetting};
+use crate::input_map::ControlScheme;
+use crate::self_collision::SelfCollision;
+use crate::model::game_model::TheGame;
+use crate::ui::button::{MenuAction, MenuButtonPressed, spawn_button, spawn_menu_button};
+use crate::ui::selector::{ListSelector, spawn_list_selector};
+use crate::ui::slider::{Slider, spawn_slider};
+use crate::ui::theme::{BUTTON_FONT_SIZE, LABEL_FONT_SIZE, TITLE_FONT_SIZE, label, row};
+use crate::ui::toggle::{Toggle, spawn_toggle};
+
+// The settings screen. It opens over whichever menu asked for it (main menu or pause menu)
+// and edits the Settings resource live, so changes can be seen straight away; closing it
+// (Back, or leaving that menu) saves them.
+
+const SETTINGS_BACKGROUND: Color = Color::srgba(0.05, 0.05, 0.07, 0.95);
+
+const WINDOW_MODES: [(WindowModeSetting, &str); 3] = [
+    (WindowModeSetting::Windowed, "Windowed"),
+    (WindowModeSetting::Borderless, "Borderless"),
+    (WindowModeSetting::Fullscreen, "Fullscreen"),
+];
+const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+const CONTROL_SCHEMES: [(ControlScheme, &str); 2] = [
+    (ControlScheme::Arrows, "Arrow keys"),
+    (ControlScheme::Wasd, "WASD"),
+];
+const SELF_COLLISION_RULES: [(SelfCollision, &str); 3] = [
+    (SelfCollision::Ignore, "Pass through"),
+    (SelfCollision::Bounce, "Bounce off"),
+    (SelfCollision::Ouroboros, "Ouroboros"),
+];
+
+#[derive(Component)]
+pub(super) struct SettingsMenu;
+
+#[derive(Component)]
+pub(super) struct MainMenuSettingsButton;
+
+// Which preference a widget on the settings screen edits.
+#[derive(Component, PartialEq, Debug, Clone, Copy)]
+pub enum SettingField {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    WindowMode,
+    Resolution,
+    ControlScheme,
+    SelfCollision,
+    ColorblindPalette,
+    DebugOverlay,
+}
+
+// Slider fields.
+pub fn set_volume(settings: &mut Settings, field: SettingField, value: f32) {
+    match field {
+        SettingField::MasterVolume => settings.master_volume = value,
+        SettingField::MusicVolume => settings.music_volume = value,
+        SettingField::SfxVolume => settings.sfx_volume = value,
+        _ => {}
+    }
+}
+
+// Toggle fields.
+pub fn set_flag(settings: &mut Settings, field: SettingField, on: bool) {
+    match field {
+        SettingField::ColorblindPalette => settings.colorblind_palette = on,
+        SettingField::DebugOverlay => settings.debug_overlay = on,
+        _ => {}
+    }
+}
+
+// List selector fields, `index` into that field's option list.
+pub fn set_selection(settings: &mut Settings, field: SettingField, index: usize) {
+    match field {
+        SettingField::WindowMode => if let Some((mode, _)) = WINDOW_MODES.get(index) { settings.window_mode = *mode },
+        SettingField::Resolution => if let Some(resolution) = RESOLUTIONS.get(index) { settings.resolution = *resolution },
+        SettingField::ControlScheme => if let Some((scheme, _)) = CONTROL_SCHEMES.get(index) { settings.control_scheme = *scheme },
+        SettingField::SelfCollision => if let Some((rule, _)) = SELF_COLLISION_RULES.get(index) { settings.self_collision = *rule },
+        _ => {}
+    }
+}
+
+// Which option a list selector field shows for `settings` (the first one if the current
+// value isn't in the list, e.g. a resolution typed into the file by hand).
+pub fn selection(settings: &Settings, field: SettingField) -> usize {
+    match field {
+        SettingField::WindowMode => WINDOW_MODES.iter().position(|(mode, _)| *mode == settings.window_mode),
+        SettingField::Resolution => RESOLUTIONS.iter().position(|r| *r == settings.resolution),
+        SettingField::ControlScheme => CONTROL_SCHEMES.iter().position(|(scheme, _)| *scheme == settings.control_scheme),
+        SettingField::SelfCollision => SELF_COLLISION_RULES.iter().position(|(rule, _)| *rule == settings.self_collision),
+        _ => None,
+    }.unwrap_or(0)
+}
+
+fn options(field: SettingField) -> Vec<String> {
+    match field {
+        SettingField::WindowMode => WINDOW_MODES.iter().map(|(_, name)| name.to_string()).collect(),
+        SettingField::Resolution => RESOLUTIONS.iter().map(|(w, h)| format!("{w}x{h}")).collect(),
+        SettingField::ControlScheme => CONTROL_SCHEMES.iter().map(|(_, name)| name.to_string()).collect(),
+        SettingField::SelfCollision => SELF_COLLISION_RULES.iter().map(|(_, name)| name.to_string()).collect(),
+        _ => vec![],
+    }
+}
+
+fn volume_slider(value: f32) -> Slider {
+    Slider { value, min: 0.0, max: 1.0, step: 0.05 }
+}
+
+pub fn spawn_main_menu_settings_button(mut commands: Commands, the_game_query: Query<&TheGame>, asset_server: Res<AssetServer>) {
+    let Ok(the_game) = the_game_query.get_single() else { return; };
+    commands.entity(the_game.root_ui_node).with_children(|parent| {
+        spawn_button(parent, &asset_server, "Settings", BUTTON_FONT_SIZE).insert((MenuAction::Settings, MainMenuSettingsButton));
+    });
+}
+
+fn spawn_settings_menu(commands: &mut Commands, the_game: &TheGame, asset_server: &AssetServer, settings: &Settings) {
+    let menu = commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                row_gap: Val::Px(10.0),
+                ..default()
+            },
+            background_color: SETTINGS_BACKGROUND.into(),
+            // covers the whole screen, so clicks mustn't reach the Start/Resume buttons underneath
+            focus_policy: FocusPolicy::Block,
+            ..default()
+        },
+        SettingsMenu,
+    )).with_children(|parent| {
+        parent.spawn(label(asset_server, "Settings", TITLE_FONT_SIZE));
+
+        let sliders = [
+            ("Master volume", SettingField::MasterVolume, settings.master_volume),
+            ("Music volume", SettingField::MusicVolume, settings.music_volume),
+            ("SFX volume", SettingField::SfxVolume, settings.sfx_volume),
+        ];
+        for (name, field, value) in sliders {
+            parent.spawn(row()).with_children(|row| {
+                row.spawn(label(asset_server, name, LABEL_FONT_SIZE));
+                spawn_slider(row, asset_server, volume_slider(value)).insert(field);
+            });
+        }
+
+        for (name, field) in [
+            ("Window mode", SettingField::WindowMode),
+            ("Resolution", SettingField::Resolution),
+            ("Controls", SettingField::ControlScheme),
+            ("Self collision", SettingField::SelfCollision),
+        ] {
+            parent.spawn(row()).with_children(|row| {
+                row.spawn(label(asset_server, name, LABEL_FONT_SIZE));
+                spawn_list_selector(row, asset_server, options(field), selection(settings, field)).insert(field);
+            });
+        }
+
+        let toggles = [
+            ("Colorblind palette", SettingField::ColorblindPalette, settings.colorblind_palette),
+            ("Debug overlay", SettingField::DebugOverlay, settings.debug_overlay),
+        ];
+        for (name, field, on) in toggles {
+            parent.spawn(row()).with_children(|row| {
+                row.spawn(label(asset_server, name, LABEL_FONT_SIZE));
+                spawn_toggle(row, asset_server, on).insert(field);
+            });
+        }
+
+        spawn_menu_button(parent, asset_server, "Back", MenuAction::CloseSettings);
+    }).id();
+    commands.entity(the_game.root_ui_node).add_child(menu);
+}
+
+// Saves and closes the settings screen if it's open.
+pub(super) fn close_settings_menu(
+    mut commands: Commands,
+    menu_query: Query<Entity, With<SettingsMenu>>,
+    settings: Res<Settings>,
+    settings_path: Res<SettingsPath>,
+) {
+    if menu_query.is_empty() {
+        return;
+    }
+    settings_path.save(&settings);
+    for entity in &menu_query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub(super) fn settings_menu_actions(
+    mut commands: Commands,
+    mut pressed_events: EventReader<MenuButtonPressed>,
+    the_game_query: Query<&TheGame>,
+    menu_query: Query<Entity, With<SettingsMenu>>,
+    settings: Res<Settings>,
+    settings_path: Res<SettingsPath>,
+    asset_server: Res<AssetServer>,
+) {
+    let Ok(the_game) = the_game_query.get_single() else { return; };
+    for MenuButtonPressed(action) in pressed_events.read() {
+        match action {
+            MenuAction::Settings if menu_query.is_empty() => {
+                spawn_settings_menu(&mut commands, the_game, &asset_server, &settings);
+            }
+            MenuAction::CloseSettings => {
+                settings_path.save(&settings);
+                for entity in &menu_query {
+                    commands.entity(entity).despawn_recursive();
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Copies widget edits into Settings. Only writes when something actually differs, so
+// freshly spawned widgets (which count as changed) don't mark Settings changed.
+pub(super) fn settings_from_widgets(
+    slider_query: Query<(&Slider, &SettingField), Changed<Slider>>,
+    toggle_query: Query<(&Toggle, &SettingField), Changed<Toggle>>,
+    selector_query: Query<(&ListSelector, &SettingField), Changed<ListSelector>>,
+    mut settings: ResMut<Settings>,
+) {
+    let mut edited = settings.clone();
+    for (slider, field) in &slider_query {
+        set_volume(&mut edited, *field, slider.value);
+    }
+    for (toggle, field) in &toggle_query {
+        set_flag(&mut edited, *field, toggle.on);
+    }
+    for (selector, field) in &selector_query {
+        set_selection(&mut edited, *field, selector.selected);
+    }
+    if edited != *settings {
+        *settings = edited;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selection_follows_the_settings() {
+        let settings = Settings { resolution: (1920, 1080), control_scheme: ControlScheme::Wasd, ..Settings::default() };
+        assert_eq!(RESOLUTIONS[selection(&settings, SettingField::Resolution)], (1920, 1080));
+        assert_eq!(CONTROL_SCHEMES[selection(&settings, SettingField::ControlScheme)].0, ControlScheme::Wasd);
+    }
+
+    #[test]
+    fn unlisted_resolution_shows_the_first_option() {
+        let settings = Settings { resolution: (800, 600), ..Settings::default() };
+        assert_eq!(selection(&settings, SettingField::Resolution), 0);
+    }
+
+    #[test]
+    fn selecting_an_option_sets_its_field() {
+        let mut settings = Settings::default();
+        set_selection(&mut settings, SettingField::WindowMode, 2);
+        assert_eq!(settings.window_mode, WindowModeSetting::Fullscreen);
+        set_selection(&mut settings, SettingField::Resolution, 99);
+        assert_eq!(settings.resolution, Settings::default().resolution);
+        set_selection(&mut settings, SettingField::SelfCollision, 2);
+        assert_eq!(settings.self_collision, SelfCollision::Ouroboros);
+    }
+
+    #[test]
+    fn widgets_only_touch_their_own_field() {
+        let mut settings = Settings::default();
+        set_volume(&mut settings, SettingField::MusicVolume, 0.3);
+        set_flag(&mut settings, SettingField::DebugOverlay, true);
+        assert_eq!(settings, Settings { music_volume: 0.3, debug_overlay: true, ..Settings::default() });
+    }
+}
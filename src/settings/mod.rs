@@ -0,0 +1,153 @@
+use std::path::PathBuf;
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode};
+use serde::{Deserialize, Serialize};
+
+mod store;
+mod menu;
+
+use crate::game_flow::despawn_all;
+use crate::input_map::ControlScheme;
+use crate::self_collision::SelfCollision;
+use crate::model::game_model::{AppState, PlayState};
+use crate::start::spawn_start_button;
+
+pub use menu::spawn_main_menu_settings_button;
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        let path = store::default_settings_path();
+        app.insert_resource(path.as_deref().map(store::load_settings).unwrap_or_default());
+        app.insert_resource(SettingsPath(path));
+        app.add_systems(Update, apply_window_settings.run_if(resource_changed::<Settings>));
+
+        app.add_systems(OnEnter(AppState::MainMenu), menu::spawn_main_menu_settings_button.after(spawn_start_button));
+        app.add_systems(OnExit(AppState::MainMenu), (menu::close_settings_menu, despawn_all::<menu::MainMenuSettingsButton>));
+        app.add_systems(OnExit(PlayState::Paused), menu::close_settings_menu);
+        app.add_systems(Update, (menu::settings_menu_actions, menu::settings_from_widgets));
+    }
+}
+
+/// Player preferences, loaded from the settings file at startup (see store.rs) and saved
+/// again when the settings screen is closed. Plugins that depend on a preference react to
+/// the resource changing (run_if(resource_changed::<Settings>)) rather than reading it once.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // volumes are 0..=1; the sound actually played is master * music or master * sfx
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub window_mode: WindowModeSetting,
+    pub resolution: (u32, u32),
+    pub control_scheme: ControlScheme,
+    // swaps colors that rely on telling red/green apart (e.g. poison food's tint)
+    pub colorblind_palette: bool,
+    // whether the gizmo diagnostics (grid.rs, key "1") start switched on
+    pub debug_overlay: bool,
+    // game mode rule for the head running into its own body
+    pub self_collision: SelfCollision,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 0.6,
+            sfx_volume: 0.8,
+            window_mode: WindowModeSetting::Windowed,
+            resolution: (1280, 720), // Bevy's default window size
+            control_scheme: ControlScheme::Arrows,
+            colorblind_palette: false,
+            debug_overlay: false,
+            self_collision: SelfCollision::Ignore,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowModeSetting {
+    Windowed,
+    Borderless,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    fn window_mode(self) -> WindowMode {
+        match self {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen,
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+}
+
+// None when the platform has no config directory - settings then only last for this session.
+#[derive(Resource)]
+struct SettingsPath(Option<PathBuf>);
+
+impl SettingsPath {
+    fn save(&self, settings: &Settings) {
+        if let Some(path) = &self.0 {
+            if let Err(e) = store::save_settings(path, settings) {
+                error!("Failed to save settings to {}: {}", path.display(), e);
+            }
+        }
+    }
+}
+
+// Only touches the window when the window settings themselves change (or on the first
+// run), so moving a volume slider doesn't undo a manual resize.
+fn apply_window_settings(
+    settings: Res<Settings>,
+    mut applied: Local<Option<(WindowModeSetting, (u32, u32))>>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let wanted = (settings.window_mode, settings.resolution);
+    if *applied == Some(wanted) {
+        return;
+    }
+    *applied = Some(wanted);
+    for mut window in &mut window_query {
+        window.mode = settings.window_mode.window_mode();
+        let (width, height) = settings.resolution;
+        window.resolution.set(width as f32, height as f32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window_app() -> App {
+        let mut app = App::new();
+        app.insert_resource(Settings::default());
+        app.add_systems(Update, apply_window_settings.run_if(resource_changed::<Settings>));
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        app.update();
+        app
+    }
+
+    fn window_size(app: &mut App) -> Vec2 {
+        app.world_mut().query::<&Window>().single(app.world()).resolution.size()
+    }
+
+    #[test]
+    fn volume_changes_leave_a_resized_window_alone() {
+        let mut app = window_app();
+        app.world_mut().query::<&mut Window>().single_mut(app.world_mut()).resolution.set(900.0, 500.0);
+        app.world_mut().resource_mut::<Settings>().master_volume = 0.3;
+        app.update();
+        assert_eq!(window_size(&mut app), Vec2::new(900.0, 500.0));
+    }
+
+    #[test]
+    fn resolution_changes_are_applied() {
+        let mut app = window_app();
+        app.world_mut().resource_mut::<Settings>().resolution = (1600, 900);
+        app.update();
+        assert_eq!(window_size(&mut app), Vec2::new(1600.0, 900.0));
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::Settings;
+use crate::save_file::save_atomically;
+
+// settings.ron under the platform config directory, e.g. ~/.config/Sylvester/settings.ron on
+// Linux or %APPDATA%\Sylvester on Windows. Unlike the high score table there's no backup or
+// version number: every field has a default (#[serde(default)]), so a missing, older or
+// unreadable file just falls back field by field / to Settings::default().
+
+const SETTINGS_FILE_NAME: &str = "settings.ron";
+
+// Where settings live, or None if the platform has no config directory (then they aren't kept).
+pub fn default_settings_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("Sylvester").join(SETTINGS_FILE_NAME))
+}
+
+pub fn parse_settings(text: &str) -> Option<Settings> {
+    ron::from_str(text).ok()
+}
+
+pub fn load_settings(path: &Path) -> Settings {
+    fs::read_to_string(path).ok()
+        .and_then(|text| parse_settings(&text))
+        .unwrap_or_default()
+}
+
+pub fn save_settings(path: &Path, settings: &Settings) -> std::io::Result<()> {
+    let text = ron::ser::to_string_pretty(settings, ron::ser::PrettyConfig::default())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    save_atomically(path, &text, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input_map::ControlScheme;
+
+    fn test_path(test_name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sylvester_settings_{}_{}", test_name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join(SETTINGS_FILE_NAME)
+    }
+
+    #[test]
+    fn defaults_when_no_file_exists() {
+        assert_eq!(load_settings(&test_path("missing")), Settings::default());
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let path = test_path("round_trip");
+        let settings = Settings { music_volume: 0.25, control_scheme: ControlScheme::Wasd, debug_overlay: true, ..Settings::default() };
+        save_settings(&path, &settings).unwrap();
+        assert_eq!(load_settings(&path), settings);
+    }
+
+    #[test]
+    fn missing_fields_take_their_defaults() {
+        let settings = parse_settings("(sfx_volume: 0.5)").unwrap();
+        assert_eq!(settings.sfx_volume, 0.5);
+        assert_eq!(settings.master_volume, Settings::default().master_volume);
+    }
+
+    #[test]
+    fn unreadable_file_gives_defaults() {
+        let path = test_path("corrupt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "(master_volume: ").unwrap();
+        assert_eq!(load_settings(&path), Settings::default());
+    }
+}
@@ -13,52 +13,83 @@ use crate::foo::*;
 use crate::grid::*;
 use crate::snake_model::*;
 use crate::trace_position_calculator::*;
+use crate::swimming::*;
+use crate::body_profile::*;
+use crate::appendage::*;
 use crate::start::*;
-use crate::model::game_model::AppState;
+use crate::model::game_model::{AppState, PlayState};
+use crate::game_flow::despawn_all;
+use crate::input_map::{InputAction, InputMap};
+use crate::gameplay_events::TierChanged;
+use crate::tunables::Tunables;
+use crate::game_clock::GameClock;
 
 pub struct SnakePlugin;
 
 impl Plugin for SnakePlugin {
     fn build (&self, app: &mut App) {
         app.add_systems(OnEnter(AppState::Playing), snake_start);
-        app.add_systems(Update, snake_update.run_if(in_state(AppState::Playing)));
+        app.add_systems(Update, (evolution_update, snake_update).chain().run_if(in_state(PlayState::Running)));
+        app.add_systems(Update, appendage_update.after(snake_update).run_if(in_state(PlayState::Running)));
+        app.add_systems(OnExit(AppState::Playing), (
+            despawn_all::<SnakeModel>,
+            despawn_all::<CreatureBodyVisualElement>,
+            despawn_all::<CreatureAppendageVisualElement>,
+        ));
     }
 }
 
 
-// Spawns the initial snake(s) and their body sprites (see creature_body_evolution.rs).
-fn snake_start (mut commands: Commands,  asset_server: Res<AssetServer>) {
-    for mut snake in snake_head_new_list() {
+// Spawns the initial snake(s), their body sprites (see creature_body_evolution.rs)
+// and their appendage sprites (see appendage.rs).
+fn snake_start (mut commands: Commands,  asset_server: Res<AssetServer>, tunables: Res<Tunables>) {
+    for mut snake in snake_head_new_list(&tunables.creature) {
         let list = spine_from_size(&mut commands, &asset_server, &mut snake);
         snake.body = list;
+        snake.appendages = spawn_appendages(&mut commands);
         commands.spawn(snake);
     }
 }
 
-fn keyboard_movement_up_down_impure(keyboard_input: &Res<ButtonInput<KeyCode>>) -> SnakeMoveDirection {
-    if keyboard_input.pressed(KeyCode::ArrowUp) { SnakeMoveDirection::Forward }
-    else if keyboard_input.pressed(KeyCode::ArrowDown) { SnakeMoveDirection::Backward }
+fn keyboard_movement_up_down_impure(keyboard_input: &Res<ButtonInput<KeyCode>>, input_map: &InputMap) -> SnakeMoveDirection {
+    if input_map.pressed(InputAction::MoveForward, keyboard_input) { SnakeMoveDirection::Forward }
+    else if input_map.pressed(InputAction::MoveBackward, keyboard_input) { SnakeMoveDirection::Backward }
     else { SnakeMoveDirection::Stop }
 }
 
-fn keyboard_rotation(keyboard_input: &Res<ButtonInput<KeyCode>>, snake: &SnakeModel, time: &Res<Time>) -> f32 {
+fn keyboard_rotation(keyboard_input: &Res<ButtonInput<KeyCode>>, input_map: &InputMap, snake: &SnakeModel, clock: &GameClock) -> f32 {
     let unit: f32 = {
-        if keyboard_input.pressed(KeyCode::ArrowRight) { -1.0 }
-        else if keyboard_input.pressed(KeyCode::ArrowLeft) { 1.0 }
+        if input_map.pressed(InputAction::TurnRight, keyboard_input) { -1.0 }
+        else if input_map.pressed(InputAction::TurnLeft, keyboard_input) { 1.0 }
         else { 0.0 }
     };
-    consts::PI / 180.0 * snake.rotation_speed_in_degrees * unit * time.delta_seconds()
+    consts::PI / 180.0 * snake.rotation_speed_in_degrees * unit * clock.delta_seconds()
 }
 
-fn draw_circle(gizmos: &mut Gizmos, position: Vec2, radius: f32, grid_query: &Query<&GridVisualDiagnostic>) {
-    if grid_draw_visual_diagnostics_info(&grid_query) {
+fn draw_circle(gizmos: &mut Gizmos, position: Vec2, radius: f32, debug_draw: &DebugDraw) {
+    if debug_draw.draws(DebugDrawCategory::SegmentCircles) {
         gizmos.circle_2d(position, radius, YELLOW);
     }
 }
 
+// The circle snake_eats_food (food_item.rs) tests food against.
+fn draw_head_hitbox(gizmos: &mut Gizmos, snake: &SnakeModel, debug_draw: &DebugDraw) {
+    if debug_draw.draws(DebugDrawCategory::HeadHitbox) {
+        gizmos.circle_2d(snake.head_pos, snake.head_radius, YELLOW);
+    }
+}
+
+// The raw trace the body is laid along, head first.
+fn draw_trace(gizmos: &mut Gizmos, snake: &SnakeModel, debug_draw: &DebugDraw) {
+    if debug_draw.draws(DebugDrawCategory::TracePolyline) {
+        let points = std::iter::once(snake.head_pos).chain(snake.trace.iter().map(|item| item.pos));
+        gizmos.linestrip_2d(points, WHITE);
+    }
+}
+
 // Debug-only: draws two shrinking circles behind the head (gizmos), roughly
 // sketching where the first couple of body segments should be, for tuning.
-fn draw_tail(gizmos: &mut Gizmos, radius: f32, snake: &SnakeModel, grid_query: &Query<&GridVisualDiagnostic>){
+fn draw_tail(gizmos: &mut Gizmos, radius: f32, snake: &SnakeModel, debug_draw: &DebugDraw){
     let mut distance = radius * 2.0;
     for i in 1..=2 {
         let shift_from_head: Vec2 = {
@@ -70,33 +101,66 @@ fn draw_tail(gizmos: &mut Gizmos, radius: f32, snake: &SnakeModel, grid_query: &
         let tail_pos = snake.head_pos + shift_from_head;
         let tail_radius = radius - (20.0 * i as f32);
         distance += 75.0;
-        draw_circle(gizmos, tail_pos, tail_radius, &grid_query);
+        draw_circle(gizmos, tail_pos, tail_radius, debug_draw);
     }
 }
 
-// Walks the trace from the head backwards, accumulating distance, until it
-// passes the distance the whole body currently needs to span. Everything
-// older (further back) than that point is no longer needed and can be pruned.
-fn get_last_trace_index_before_clean(snake: &SnakeModel, gizmos: &mut Gizmos) -> i64 {
-    let mut current_pos = snake.head_pos;
-    let mut total_distance = 0.0;
+// Everything older than the first trace point the tail's far edge reaches is no longer
+// needed and can be pruned.
+fn get_last_trace_index_before_clean(snake: &SnakeModel) -> i64 {
+    last_needed_trace_index(snake.head_pos, &snake.trace, body_length(snake))
+}
 
-    let mut last_trace_index_before_clean = 0;
-    for i in snake.trace.iter() {
-        total_distance += current_pos.distance(i.pos);
-        current_pos = i.pos;
+// Where one body segment sits on the trace and which way it faces - what draw_nodes
+// places the sprite from, before the swim wiggle moves it sideways. svg_export.rs and
+// trace_fixture.rs read the same poses, so what they record is what was drawn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SegmentPose {
+    pub distance_from_head: f32,
+    pub position: Vec2,
+    pub radius: f32,
+    // smoothed angle the sprite is rotated to, in radians
+    pub angle: f32,
+    // raw angle of the trace segment it landed on
+    pub trace_direction: f32,
+}
 
-        if total_distance > 20.0 + snake.size * snake.node_radius * 2.0 {
-            last_trace_index_before_clean = i.index;
-            break;
-        }
+// Looks up snake.body[segment]'s distance_from_head (see body_profile.rs) back along the
+// head's trace.
+pub fn segment_pose(snake: &SnakeModel, segment: usize) -> SegmentPose {
+    let node = &snake.body[segment];
+    let node_calc_result = calculate_node_pos_traced_on_distance_from_head(
+        snake.head_pos,
+        snake.head_direction_angle,
+        snake.trace.iter().map(|p| p.pos),
+        node.distance_from_head,
+    );
+    let directions = node_calc_result.directions;
+    let angle = interpolate_direction(
+        directions.direction_previous,
+        directions.direction_current,
+        directions.direction_next,
+        directions.segment_distance_fraction,
+    );
+    SegmentPose {
+        distance_from_head: node.distance_from_head,
+        position: node_calc_result.position,
+        radius: node.radius,
+        angle,
+        trace_direction: directions.direction_current,
     }
-    return last_trace_index_before_clean;
+}
+
+// Poses of the segments draw_nodes shows: the head, then up to snake.size body segments.
+pub fn segment_poses(snake: &SnakeModel) -> Vec<SegmentPose> {
+    (0..snake.body.len())
+        .take(snake.size as usize + 1)
+        .map(|segment| segment_pose(snake, segment))
+        .collect()
 }
 
 // For each body segment (0 = head, up to snake.size), computes its position/rotation
-// by looking up that far back along the head's trace (calculate_node_pos_traced_on_distance_from_head),
-// then moves the corresponding pre-spawned sprite (snake.body[i]) there. Called twice
+// (segment_pose), then moves the corresponding pre-spawned sprite (snake.body[i]) there. Called twice
 // per frame in snake_update: once to get positions for pruning, once to actually draw.
 // The last body segment (the tail) always gets the SpineEnd sprite so the small dot
 // on it points outwards, away from the rest of the body; every other segment gets SpinePart.
@@ -105,16 +169,17 @@ fn draw_nodes(
     gizmos: &mut Gizmos,
     asset_server: &Res<AssetServer>,
     query_visual_element: &mut Query<(&mut Transform, &mut Handle<Image>), With<CreatureBodyVisualElement>>,
+    debug_draw: &DebugDraw,
 ) {
     let mut current_pos = snake.head_pos;
     let step = snake.tracing_step;
     let mut color_change = 0;
 
-    let body_sprite_scale = BASE_BODY_SPRITE_SCALE * (snake.node_radius / BASE_NODE_RADIUS);
-    let end_sprite_scale = BASE_END_SPRITE_SCALE * (snake.node_radius / BASE_NODE_RADIUS);
     let visible_segment_count = snake.size as i32;
+    let swim_amplitude = swim_amplitude(snake.current_speed, snake.evolution_tier, snake.node_radius);
     let part_texture: Handle<Image> = asset_server.load("SpinePart.png");
     let end_texture: Handle<Image> = asset_server.load("SpineEnd.png");
+    snake.segment_positions.clear();
 
     for i in 0..snake.body.len() as i32 {
         if i > visible_segment_count {
@@ -124,40 +189,38 @@ fn draw_nodes(
             continue;
         }
 
-        let distance_from_head = i as f32 * (snake.node_radius * 2.0);
-        let trace_positions_iterator = snake.trace.iter().map(|p| p.pos);
-        let node_calc_result = calculate_node_pos_traced_on_distance_from_head(
-            snake.head_pos, 
-            snake.head_direction_angle,
-            trace_positions_iterator, 
-            distance_from_head
-        );
-        
-        //gizmos.circle_2d(node_pos, snake.node_radius, BLUE);
-        
-        let color = Color::hsl(360.0 * color_change as f32 / step as f32, 0.95, 0.7);
-        color_change += 1; 
-
-        gizmos.line_2d(current_pos, node_calc_result.position, color);
+        let pose = segment_pose(snake, i as usize);
+        snake.segment_positions.push(pose.position);
+
+        if debug_draw.draws(DebugDrawCategory::SegmentCircles) {
+            let color = Color::hsl(360.0 * color_change as f32 / step as f32, 0.95, 0.7);
+            gizmos.line_2d(current_pos, pose.position, color);
+            gizmos.circle_2d(pose.position, pose.radius, BLUE);
+        }
+        color_change += 1;
+
+        if debug_draw.draws(DebugDrawCategory::SplineTangents) {
+            let tangent = Vec2::from_angle(pose.trace_direction) * pose.radius * 2.0;
+            gizmos.line_2d(pose.position, pose.position + tangent, FUCHSIA);
+        }
+
         if i != 0 {
-            current_pos = node_calc_result.position;
+            current_pos = pose.position;
         }
 
         let snake_node = {
             let is_tail = i != 0 && i == visible_segment_count;
             let (mut node, mut texture) = query_visual_element.get_mut(snake.body[i as usize].node_type).unwrap();
-            node.translation = Vec3::new(node_calc_result.position.x, node_calc_result.position.y, 0.0);
-            let scale = if is_tail { end_sprite_scale } else { body_sprite_scale };
+            // the wiggle only moves the sprite - the trace (and pose) stays on the centerline
+            let normalized_pos = i as f32 / visible_segment_count.max(1) as f32;
+            let lateral_offset = swim_lateral_offset(normalized_pos, snake.swim_phase, swim_amplitude);
+            let sprite_pos = apply_lateral_offset(pose.position, pose.trace_direction, lateral_offset);
+            node.translation = Vec3::new(sprite_pos.x, sprite_pos.y, 0.0);
+            let base_scale = if is_tail { BASE_END_SPRITE_SCALE } else { BASE_BODY_SPRITE_SCALE };
+            let scale = base_scale * (pose.radius / BASE_NODE_RADIUS);
             node.scale = Vec3::new(scale, scale, node.scale.z);
 
-            //println!("{:?}", node_calc_result.directions.segment_distance_fraction.to_string());
-            let a = interpolate_direction(
-                node_calc_result.directions.direction_previous,
-                node_calc_result.directions.direction_current,
-                node_calc_result.directions.direction_next,
-                node_calc_result.directions.segment_distance_fraction,
-            );
-            node.rotation = Quat::from_rotation_z(a + PI / 2.0 + PI);
+            node.rotation = Quat::from_rotation_z(pose.angle + PI / 2.0 + PI);
 
             if i != 0 {
                 *texture = if is_tail { end_texture.clone() } else { part_texture.clone() };
@@ -166,39 +229,59 @@ fn draw_nodes(
     }
 }
 
+// Moves the creature between evolution tiers as its size crosses the Tunables.tiers
+// thresholds, easing node_radius toward the new tier before snake_update lays out the body.
+fn evolution_update(
+    mut snake_query: Query<&mut SnakeModel>,
+    tunables: Res<Tunables>,
+    clock: Res<GameClock>,
+    mut tier_changed_events: EventWriter<TierChanged>,
+) {
+    for mut snake in &mut snake_query {
+        if let Some(tier_changed) = update_evolution_transition(&mut snake, &tunables.tiers, clock.delta_seconds()) {
+            tier_changed_events.send(tier_changed);
+        }
+    }
+}
+
 // Main per-frame snake system: applies keyboard input to rotate/move the head,
 // recomputes and draws body segment positions, then prunes trace history that's
 // no longer needed (see get_last_trace_index_before_clean).
-fn snake_update (
+pub(crate) fn snake_update (
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut gizmos: Gizmos,
     mut snake_query: Query<&mut SnakeModel>,
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-    grid_query: Query<&GridVisualDiagnostic>,
+    input_map: Res<InputMap>,
+    clock: Res<GameClock>,
+    debug_draw: Res<DebugDraw>,
     mut query_visual_element: Query<(&mut Transform, &mut Handle<Image>), With<CreatureBodyVisualElement>>,
 ) {
     for mut snake in &mut snake_query {
         ensure_body_capacity(&mut commands, &asset_server, &mut snake);
 
-        update_evolution_transition(&mut snake, time.delta_seconds());
+        update_body_layout(&mut snake);
 
-        snake.head_direction_angle += keyboard_rotation(&keyboard_input, &snake, &time) * (snake.movement_speed / 4.0);
+        let rotation = keyboard_rotation(&keyboard_input, &input_map, &snake, &clock) * (snake.movement_speed / 4.0);
+        snake.head_direction_angle += rotation;
+        snake.turn_rate = if clock.delta_seconds() > 0.0 { rotation / clock.delta_seconds() } else { 0.0 };
 
-        let keyboard_up_down_input: SnakeMoveDirection = keyboard_movement_up_down_impure(&keyboard_input);
-        head_move_pure(keyboard_up_down_input, time.delta_seconds(), &mut snake);
+        let keyboard_up_down_input: SnakeMoveDirection = keyboard_movement_up_down_impure(&keyboard_input, &input_map);
+        head_move_pure(keyboard_up_down_input, clock.delta_seconds(), &mut snake);
+        advance_swim_phase(&mut snake, clock.delta_seconds());
 
-        let node_pos = draw_nodes(&mut snake, &mut gizmos, &asset_server, &mut query_visual_element);
+        let node_pos = draw_nodes(&mut snake, &mut gizmos, &asset_server, &mut query_visual_element, &debug_draw);
 
-        let last_trace_index_before_clean = get_last_trace_index_before_clean(&snake, &mut gizmos);
-        clear_extra_traces(&mut snake.trace, last_trace_index_before_clean);
+        let last_trace_index_before_clean = get_last_trace_index_before_clean(&snake);
+        snake.trace_pruned_count += clear_extra_traces(&mut snake.trace, last_trace_index_before_clean);
 
-        draw_circle(&mut gizmos, snake.head_pos, snake.head_radius, &grid_query); // draws hidden snake head in gizmos
+        draw_head_hitbox(&mut gizmos, &snake, &debug_draw); // draws hidden snake head in gizmos
 
-        draw_tail(&mut gizmos, snake.head_radius, &snake, &grid_query);
+        draw_tail(&mut gizmos, snake.head_radius, &snake, &debug_draw);
+        draw_trace(&mut gizmos, &snake, &debug_draw);
 
-        draw_nodes(&mut snake, &mut gizmos, &asset_server, &mut query_visual_element);
+        draw_nodes(&mut snake, &mut gizmos, &asset_server, &mut query_visual_element, &debug_draw);
 
         let snake_head = {
             let (mut head, _texture) = query_visual_element.get_mut(snake.body[0].node_type).unwrap();
@@ -2,6 +2,11 @@ use bevy::math::Vec2;
 use bevy::prelude::{Component, Entity};
 use std::collections::LinkedList;
 use std::f32::consts::PI;
+use serde::{Deserialize, Serialize};
+
+use crate::appendage::Appendage;
+use crate::body_profile::BodyProfile;
+use crate::tunables::CreatureTunables;
 
 // A single recorded point along the path the snake's head has travelled.
 // The body segments don't move independently - their positions are derived
@@ -15,17 +20,19 @@ pub struct TraceItem {
     pub index: i64,
 }
 
-#[derive(PartialEq, Debug, Clone, Copy)]
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum SnakeSpineNodeType {
     Big,
     Medium,
     Small,
 }
 
-// One visual body segment: how far along the trace it sits, and the entity
-// (sprite) whose Transform gets updated to that position every frame.
+// One visual body segment: how far along the trace it sits, how thick it is, and the
+// entity (sprite) whose Transform gets updated to that position every frame.
+// distance_from_head and radius are recomputed from SnakeModel.body_profile each frame (see body_profile.rs).
 pub struct SnakeSpineNode{
     pub distance_from_head: f32,
+    pub radius: f32,
     pub node_type: Entity
 }
 
@@ -50,14 +57,27 @@ pub struct SnakeModel {
     pub trace_counter: i64,
     // history of head positions, newest first (front), used to place body segments
     pub trace: LinkedList<TraceItem>,
+    // how many trace items clear_extra_traces has dropped since the run started, shown by the debug inspector
+    pub trace_pruned_count: usize,
     // minimum distance the head must travel before a new trace point is recorded
     pub tracing_step: f32,
+    // how far the recorded trace may stray from where the head really went; straight
+    // stretches within it are merged into one longer trace segment (0 = keep every point)
+    pub trace_tolerance: f32,
+    // head positions merged away into the newest trace segment since it started, oldest
+    // first - kept so a later merge can check it isn't bending the trace away from them
+    pub trace_merged: Vec<Vec2>,
     // NOT number of foods eaten by snake // number of nodes drawn
     pub size: f32,
     pub node_radius: f32,
+    // per-creature thickness curve along the body, scaled by node_radius
+    pub body_profile: BodyProfile,
 
     // visual body segments (sprites), spawned once up front and repositioned each frame
     pub body: Vec<SnakeSpineNode>,
+    // where draw_nodes laid the visible segments out along the trace this frame (0 = head),
+    // before the swim wiggle - what self-collision tests the head against
+    pub segment_positions: Vec<Vec2>,
 
     // last evolution tier committed to; compared each frame against the size-derived
     // tier to detect a change and kick off a new node_radius transition
@@ -66,6 +86,16 @@ pub struct SnakeModel {
     pub evolution_transition_start_radius: f32,
     // seconds since the current transition began; >= SCALE_TRANSITION_DURATION means settled
     pub evolution_transition_elapsed: f32,
+
+    // how fast the head actually moved on the last frame (always >= 0), drives the swimming wiggle
+    pub current_speed: f32,
+    // current angle (radians, 0..2PI) of the swimming wave at the head, see swimming.rs
+    pub swim_phase: f32,
+    // how fast the heading changed on the last frame, in radians per second (positive = turning left)
+    pub turn_rate: f32,
+
+    // fins/feelers/spikes attached to body segments, spawned once up front (see appendage.rs)
+    pub appendages: Vec<Appendage>,
 }
 
 pub enum  SnakeMoveDirection {
@@ -74,7 +104,12 @@ pub enum  SnakeMoveDirection {
     Stop
 }
 
+// A creature with the shipped handling numbers - what tests build on.
 pub fn snake_model_new(i: i32) -> SnakeModel {
+    snake_model_with(i, &CreatureTunables::default())
+}
+
+pub fn snake_model_with(i: i32, creature: &CreatureTunables) -> SnakeModel {
     let head_pos = Vec2::new(0.0, i as f32 * -100.0);
     let trace_item = TraceItem {
         pos: head_pos,
@@ -83,49 +118,66 @@ pub fn snake_model_new(i: i32) -> SnakeModel {
     SnakeModel {
         head_pos,
         head_direction_angle: PI / 2.0,
-        head_radius: 50.0,
-        movement_speed: 150.0,
-        rotation_speed_in_degrees: 3.0,
+        head_radius: creature.head_radius,
+        movement_speed: creature.movement_speed,
+        rotation_speed_in_degrees: creature.rotation_speed_in_degrees,
         trace_counter: 0,
         trace: LinkedList::from([trace_item]),
-        tracing_step: 10.0,
-        size: 5.0,
+        trace_pruned_count: 0,
+        tracing_step: creature.tracing_step,
+        trace_tolerance: creature.trace_tolerance,
+        trace_merged: vec![],
+        size: creature.starting_size,
         node_radius: 10.0,
+        body_profile: BodyProfile::default(),
         body: vec![],
+        segment_positions: vec![],
         evolution_tier: SnakeSpineNodeType::Small,
         evolution_transition_start_radius: 10.0,
         evolution_transition_elapsed: 1.0, // starts settled (>= SCALE_TRANSITION_DURATION)
+        current_speed: 0.0,
+        swim_phase: 0.0,
+        turn_rate: 0.0,
+        appendages: vec![],
     }
 
 }
 
-pub fn snake_head_new_list() -> Vec<SnakeModel> {
+pub fn snake_head_new_list(creature: &CreatureTunables) -> Vec<SnakeModel> {
     let mut result: Vec<SnakeModel> = Vec::new();
     for i in 0..1 {
-        result.push(snake_model_new(i));
+        result.push(snake_model_with(i, creature));
     }
     result
 }
 
 // Drops trace items from the back (oldest) of the list whose index is below
-// max_index, since the snake's body no longer reaches that far back.
-pub fn clear_extra_traces(list: &mut LinkedList<TraceItem>, max_index: i64) {
+// max_index, since the snake's body no longer reaches that far back. Returns how many were dropped.
+pub fn clear_extra_traces(list: &mut LinkedList<TraceItem>, max_index: i64) -> usize {
+    let mut pruned = 0;
     loop {
         match list.back() {
-            None => { return; },
+            None => { return pruned; },
             Some(trace_item) => {
                 if trace_item.index < max_index {
                     list.pop_back();
+                    pruned += 1;
                 }
-                else { return; }
+                else { return pruned; }
             }
         }
     }
 }
 
+// Longest a merged trace segment may get, in tracing steps. Keeps the direction
+// smoothing in draw_nodes (which works per trace segment) from spreading too far.
+const MAX_MERGED_TRACE_STEPS: f32 = 8.0;
+
 // Moves the head one frame's worth of distance in its current facing direction,
 // and records a new trace point once the head has moved at least `tracing_step`
 // away from the last recorded point (so the trace isn't updated every frame).
+// See record_trace_point for how straight stretches are kept sparse.
+// Backward doesn't record anything: the head backs up along the trace instead (retrace_backwards).
 pub fn head_move_pure(keyboard_up_down_input: SnakeMoveDirection, time_delta_seconds: f32, snake: &mut SnakeModel) {
     let keyboard_up_down_input_ratio: f32 = match keyboard_up_down_input {
         SnakeMoveDirection::Forward => { 1.0 }
@@ -133,6 +185,11 @@ pub fn head_move_pure(keyboard_up_down_input: SnakeMoveDirection, time_delta_sec
         SnakeMoveDirection::Stop => { 0.0 }
     };
     let movement = keyboard_up_down_input_ratio * snake.movement_speed;
+    snake.current_speed = movement.abs();
+    if movement < 0.0 {
+        retrace_backwards(snake, -movement * time_delta_seconds);
+        return;
+    }
     let x_head = f32::cos(snake.head_direction_angle) * movement * time_delta_seconds;
     let y_head = f32::sin(snake.head_direction_angle) * movement * time_delta_seconds;
 
@@ -142,18 +199,106 @@ pub fn head_move_pure(keyboard_up_down_input: SnakeMoveDirection, time_delta_sec
     let distance_between = (snake.head_pos + new_head_move).distance(last_trace_point.pos);
     if distance_between >= snake.tracing_step {
         let point = snake.head_pos + new_head_move;
-        snake.trace_counter += 1;
-            let trace_item = TraceItem {
-                pos: point,
-                index: snake.trace_counter
-            };
-            snake.trace.push_front(trace_item);
+        record_trace_point(snake, point);
     }
 
     snake.head_pos += new_head_move;
 
 }
 
+// Walks the head `distance` back along its own trace, so the body backs up the way it
+// came instead of folding onto itself. Trace points the head passes are consumed (and
+// the trace counter goes back with them, so forward recording carries on from there).
+// Past the oldest point the path is extrapolated straight back; the body segments
+// beyond the end of the trace already are (calculate_node_pos_traced_on_distance_from_head).
+fn retrace_backwards(snake: &mut SnakeModel, distance: f32) {
+    let mut remaining = distance;
+    while remaining > 0.0 {
+        if snake.trace.len() == 1 {
+            extend_trace_back(snake);
+        }
+        let behind = snake.trace.front().unwrap().pos;
+        let to_behind = behind - snake.head_pos;
+        let gap = to_behind.length();
+        if gap > remaining {
+            snake.head_pos += to_behind / gap * remaining;
+            break;
+        }
+        snake.head_pos = behind;
+        remaining -= gap;
+        snake.trace.pop_front();
+        snake.trace_merged.clear();
+        snake.trace_counter = snake.trace.front().unwrap().index;
+    }
+    // still facing the way the path goes, like the body segments behind it
+    let behind = snake.trace.front().unwrap().pos;
+    if let Some(direction) = (snake.head_pos - behind).try_normalize() {
+        snake.head_direction_angle = direction.y.atan2(direction.x);
+    }
+}
+
+// Adds a point tracing_step beyond the oldest one, continuing the trace's last
+// direction (or straight back from the head, for a trace of one point).
+fn extend_trace_back(snake: &mut SnakeModel) {
+    let mut oldest_two = snake.trace.iter().rev().take(2).map(|item| item.pos);
+    let oldest = snake.trace.back().unwrap();
+    let direction = match (oldest_two.next(), oldest_two.next()) {
+        (Some(last), Some(before_last)) => (last - before_last).try_normalize(),
+        _ => None,
+    }.unwrap_or(-Vec2::from_angle(snake.head_direction_angle));
+    let extended = TraceItem { pos: oldest.pos + direction * snake.tracing_step, index: oldest.index - 1 };
+    snake.trace.push_back(extended);
+}
+
+// Adds `point` to the front of the trace - or, while the head is going (nearly) straight,
+// moves the newest trace point up to it instead, so a straight run is one long segment
+// and only turns get a point every tracing_step. A merge only happens if the
+// newest point and every point merged before it stay within trace_tolerance of the new
+// segment, so the simplified trace never strays further than that from the dense one.
+pub fn record_trace_point(snake: &mut SnakeModel, point: Vec2) {
+    snake.trace_counter += 1;
+    let index = snake.trace_counter;
+    let mut newest_two = snake.trace.iter().take(2).map(|item| item.pos);
+    if let (Some(newest), Some(anchor)) = (newest_two.next(), newest_two.next()) {
+        let span = anchor.distance(point);
+        let stays_close = snake.trace_merged.iter().chain(std::iter::once(&newest))
+            .all(|&pos| distance_to_segment(pos, anchor, point) <= snake.trace_tolerance);
+        if snake.trace_tolerance > 0.0 && span <= snake.tracing_step * MAX_MERGED_TRACE_STEPS && stays_close {
+            snake.trace_merged.push(newest);
+            *snake.trace.front_mut().unwrap() = TraceItem { pos: point, index };
+            return;
+        }
+    }
+    snake.trace_merged.clear();
+    snake.trace.push_front(TraceItem { pos: point, index });
+}
+
+fn distance_to_segment(pos: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let length_squared = ab.length_squared();
+    if length_squared == 0.0 {
+        return pos.distance(a);
+    }
+    let t = ((pos - a).dot(ab) / length_squared).clamp(0.0, 1.0);
+    pos.distance(a + ab * t)
+}
+
+// Index of the first trace point at least `length` back along the trace from the head.
+// Everything older only trails behind the tail and can be pruned. 0 (prune nothing)
+// while the trace doesn't reach that far yet.
+pub fn last_needed_trace_index(head_pos: Vec2, trace: &LinkedList<TraceItem>, length: f32) -> i64 {
+    let mut current_pos = head_pos;
+    let mut total_distance = 0.0;
+    for item in trace.iter() {
+        total_distance += current_pos.distance(item.pos);
+        current_pos = item.pos;
+        if total_distance >= length {
+            return item.index;
+        }
+    }
+    0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,7 +350,9 @@ mod tests {
         let expected_move = Vec2::new(0.0, -30.0);
 
         assert_vec2_eq(snake.head_pos, expected_move);
-        assert_eq!(snake.trace, traces_original);
+        // nothing to back up along yet: the start point is consumed and the path extrapolated behind it
+        assert_ne!(snake.trace, traces_original);
+        assert_vec2_eq(snake.trace.front().unwrap().pos, Vec2::new(0.0, -50.0));
     }
 
     #[test]
@@ -233,7 +380,9 @@ mod tests {
         let expected_move = Vec2::new(0.0, 30.0);
 
         assert_vec2_eq(snake.head_pos, expected_move);
-        assert_eq!(snake.trace, traces_original);
+        assert_ne!(snake.trace, traces_original);
+        assert_vec2_eq(snake.trace.front().unwrap().pos, Vec2::new(0.0, 50.0));
+        assert_float_eq(snake.head_direction_angle, -PI / 2.0);
     }
 
     #[test]
@@ -324,7 +473,7 @@ mod tests {
                 index: i,
             })
         }
-        clear_extra_traces(&mut list, 6);
+        assert_eq!(clear_extra_traces(&mut list, 6), 6);
         let mut actual: Vec<TraceItem> = Vec::new();
         for i in list.iter(){
             actual.push(i.clone());
@@ -350,4 +499,140 @@ mod tests {
         ];
         assert_eq!(actual, expected)
     }
+
+    // Swims `frames` frames at 60fps, turning `turn_rate` radians per second.
+    fn swim(snake: &mut SnakeModel, frames: usize, turn_rate: f32) {
+        for _ in 0..frames {
+            snake.head_direction_angle += turn_rate / 60.0;
+            head_move_pure(SnakeMoveDirection::Forward, 1.0 / 60.0, snake);
+        }
+    }
+
+    // straight, a tight turn, a gentle curve and straight again
+    fn mixed_course(snake: &mut SnakeModel) {
+        swim(snake, 120, 0.0);
+        swim(snake, 60, 4.0);
+        swim(snake, 120, -0.4);
+        swim(snake, 60, 0.0);
+    }
+
+    fn dense(mut snake: SnakeModel) -> SnakeModel {
+        snake.trace_tolerance = 0.0;
+        snake
+    }
+
+    #[test]
+    fn straight_runs_are_kept_sparse() {
+        let mut sparse = snake_model_new(0);
+        let mut every_step = dense(snake_model_new(0));
+        swim(&mut sparse, 240, 0.0);
+        swim(&mut every_step, 240, 0.0);
+        assert!(sparse.trace.len() * 4 < every_step.trace.len());
+        // no merged segment is longer than the cap
+        let points: Vec<Vec2> = sparse.trace.iter().map(|item| item.pos).collect();
+        for pair in points.windows(2) {
+            assert!(pair[0].distance(pair[1]) <= sparse.tracing_step * MAX_MERGED_TRACE_STEPS + 0.001);
+        }
+    }
+
+    #[test]
+    fn tight_turns_keep_every_point() {
+        let mut sparse = snake_model_new(0);
+        let mut every_step = dense(snake_model_new(0));
+        swim(&mut sparse, 60, 4.0);
+        swim(&mut every_step, 60, 4.0);
+        assert_eq!(sparse.trace.len(), every_step.trace.len());
+    }
+
+    #[test]
+    fn segments_land_within_tolerance_of_the_dense_trace() {
+        let mut sparse = snake_model_new(0);
+        let mut every_step = dense(snake_model_new(0));
+        mixed_course(&mut sparse);
+        mixed_course(&mut every_step);
+        assert!(sparse.trace.len() < every_step.trace.len());
+
+        let position = |snake: &SnakeModel, distance: f32| {
+            crate::trace_position_calculator::calculate_node_pos_traced_on_distance_from_head(
+                snake.head_pos,
+                snake.head_direction_angle,
+                snake.trace.iter().map(|item| item.pos),
+                distance,
+            ).position
+        };
+        let mut distance = 1.0;
+        while distance < 700.0 {
+            let error = position(&sparse, distance).distance(position(&every_step, distance));
+            assert!(error <= 2.0 * sparse.trace_tolerance, "{error} at {distance}");
+            distance += 5.0;
+        }
+    }
+
+    #[test]
+    fn last_needed_point_is_the_first_one_past_the_length() {
+        let trace = LinkedList::from([
+            TraceItem { pos: Vec2::new(0.0, -10.0), index: 3 },
+            TraceItem { pos: Vec2::new(0.0, -50.0), index: 2 },
+            TraceItem { pos: Vec2::new(0.0, -60.0), index: 1 },
+        ]);
+        assert_eq!(last_needed_trace_index(Vec2::ZERO, &trace, 30.0), 2);
+        assert_eq!(last_needed_trace_index(Vec2::ZERO, &trace, 50.0), 2);
+        assert_eq!(last_needed_trace_index(Vec2::ZERO, &trace, 100.0), 0);
+    }
+
+    fn back_up(snake: &mut SnakeModel, frames: usize) {
+        for _ in 0..frames {
+            head_move_pure(SnakeMoveDirection::Backward, 1.0 / 60.0, snake);
+        }
+    }
+
+    #[test]
+    fn backing_up_retraces_the_path_it_came_along() {
+        let mut snake = dense(snake_model_new(0));
+        swim(&mut snake, 60, 0.0);
+        let before_turn = snake.head_pos;
+        let points_before_turn = snake.trace.len();
+        swim(&mut snake, 60, 3.0);
+        let trace_after_turn = snake.trace.len();
+
+        back_up(&mut snake, 60);
+        // back where the turn started, without new points being added on the way
+        assert!(snake.head_pos.distance(before_turn) < snake.tracing_step);
+        assert!(snake.trace.len() <= points_before_turn + 1);
+        assert!(snake.trace.len() < trace_after_turn);
+        // and facing up the straight part again
+        assert_float_eq(snake.head_direction_angle, PI / 2.0);
+    }
+
+    #[test]
+    fn trace_counter_follows_consumed_points() {
+        let mut snake = dense(snake_model_new(0));
+        swim(&mut snake, 60, 1.0);
+        back_up(&mut snake, 30);
+        assert_eq!(snake.trace_counter, snake.trace.front().unwrap().index);
+
+        // going forward again carries on numbering from there, newest first
+        swim(&mut snake, 30, -1.0);
+        let indices: Vec<i64> = snake.trace.iter().map(|item| item.index).collect();
+        assert!(indices.windows(2).all(|pair| pair[0] > pair[1]));
+        assert_eq!(snake.trace_counter, indices[0]);
+    }
+
+    #[test]
+    fn backing_up_past_the_start_extends_the_trace_behind() {
+        let mut snake = snake_model_new(0);
+        swim(&mut snake, 30, 0.0);
+        back_up(&mut snake, 60);
+        // twice as far back as it came: below the start, still heading up, trace behind the head
+        assert_vec2_eq(snake.head_pos, Vec2::new(0.0, -75.0));
+        assert_float_eq(snake.head_direction_angle, PI / 2.0);
+        assert!(snake.trace.front().unwrap().pos.y < snake.head_pos.y);
+
+        // the extrapolated points sort below everything recorded and are pruned like any other
+        let oldest = snake.trace.back().unwrap().index;
+        assert!(oldest < 0);
+        swim(&mut snake, 120, 0.0);
+        clear_extra_traces(&mut snake.trace, 1);
+        assert!(snake.trace.iter().all(|item| item.index >= 1));
+    }
 }
@@ -1,30 +1,33 @@
 use bevy::{ecs::system::EntityCommands, gizmos, math::VectorSpace, prelude::*};
 use crate::model::game_model::*; 
 use crate::model::game_test::*;
+use crate::ui::button::{MenuAction, spawn_menu_button};
 
 
 pub struct StartPlugin; 
 
 impl Plugin for StartPlugin {
     fn build (&self, app: &mut App) {
-        app.add_systems(Startup, create_game);
-        app.add_systems(Update, button_system);
+        // the initial OnEnter(MainMenu) runs before Startup, so the game root is created there
+        app.add_systems(OnEnter(AppState::MainMenu), (create_game, spawn_start_button).chain());
+        app.add_systems(OnExit(AppState::MainMenu), despawn_start_button);
     }
 }
 
-const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
-const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
-//const PRESSED_BUTTON: Color = Color::srgb(0.35, 0.75, 0.35);
-
-// Spawns the root UI node and the single TheGame entity that tracks it,
-// then adds the Start button as its child.
-fn create_game(mut commands: Commands, asset_server: Res<AssetServer>) {
+// Spawns the root UI node and the single TheGame entity that tracks it, the first time
+// the main menu is entered. The Start button is added right after (spawn_start_button).
+fn create_game(mut commands: Commands, the_game_query: Query<(), With<TheGame>>) {
+    if !the_game_query.is_empty() {
+        return;
+    }
 
     // All UI must be under this root node component.
-    let mut node_bundle_entity_commands: EntityCommands<'_> = commands.spawn(NodeBundle {
+    let node_bundle_entity_commands: EntityCommands<'_> = commands.spawn(NodeBundle {
         style: Style {
             width: Val::Percent(100.0),
             height: Val::Percent(100.0),
+            // menus stack their parts (buttons, leaderboard) top to bottom
+            flex_direction: FlexDirection::Column,
             align_items: AlignItems::Center,
             justify_content: JustifyContent::Center,
             ..default()
@@ -32,52 +35,34 @@ fn create_game(mut commands: Commands, asset_server: Res<AssetServer>) {
         ..default()
     });
 
-    let mut the_game = TheGame { 
+    let the_game = TheGame { 
         root_ui_node: node_bundle_entity_commands.id(),
         start_button_entity: None 
     };
-    create_start_button(&mut the_game, &mut node_bundle_entity_commands, &asset_server);
     commands.spawn(the_game);
 }
 
+// (Re)creates the Start button every time the main menu is shown - at launch and when
+// coming back from a finished run.
+pub fn spawn_start_button(mut commands: Commands, mut the_game_query: Query<&mut TheGame>, asset_server: Res<AssetServer>) {
+    let mut the_game = the_game_query.single_mut();
+    if the_game.start_button_entity.is_some() {
+        return;
+    }
+    let root_ui_node = the_game.root_ui_node;
+    create_start_button(&mut the_game, &mut commands.entity(root_ui_node), &asset_server);
+}
+
 fn create_start_button(the_game: &mut TheGame, node_bundle_entity_command: &mut EntityCommands<'_>, asset_server: &Res<AssetServer>) {
     node_bundle_entity_command.with_children(|parent| {
-        let mut start_button_bundle_entity = parent.spawn(ButtonBundle {
-            style: Style {
-                width: Val::Px(150.0),
-                height: Val::Px(65.0),
-                border: UiRect::all(Val::Px(5.0)),
-                // horizontally center child text
-                justify_content: JustifyContent::Center,
-                // vertically center child text
-                align_items: AlignItems::Center,
-                ..default()
-            },
-            border_color: BorderColor(Color::BLACK),
-            border_radius: BorderRadius::MAX,
-            background_color: NORMAL_BUTTON.into(),
-            ..default()
-        });
-        let start_button_bundle_entity_id = start_button_bundle_entity.id();
+        let start_button_bundle_entity_id = spawn_menu_button(parent, asset_server, "Start", MenuAction::StartGame);
         the_game.start_button_entity = Some(start_button_bundle_entity_id);
-        
-        start_button_bundle_entity.with_children(|parent| {
-            let start_text_bundle_entity = parent.spawn(TextBundle::from_section(
-                "Start",
-                TextStyle {
-                    font: asset_server.load("MovistarTextRegular.ttf"),
-                    font_size: 40.0,
-                    color: Color::srgb(0.9, 0.9, 0.9),
-                },
-            ));
-            println!("start_text_bundle_entity: {}", start_text_bundle_entity.id().index().to_string());
-        });
     });
 }
 
 fn despawn_start_button(
-    the_game_query: &mut Query<&mut TheGame>,
-    commands: &mut Commands,
+    mut the_game_query: Query<&mut TheGame>,
+    mut commands: Commands,
 ){
     let mut the_game = the_game_query.single_mut();
     match the_game.start_button_entity {
@@ -94,38 +79,3 @@ fn despawn_start_button(
         }
     }
 }
-
-// Handles hover/click styling for any Button, and despawns the Start button
-// (via despawn_start_button) when it's pressed. Pressing Start also moves
-// AppState to Playing, which is what actually unblocks the snake/food
-// gameplay systems (they're registered with .run_if(in_state(AppState::Playing))).
-pub fn button_system(
-    mut the_game_query: Query<&mut TheGame>,
-    mut start_geme_button_query: Query<
-        (
-            &Interaction,
-            &mut BackgroundColor,
-            &mut BorderColor,
-        ),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut commands: Commands,
-    mut next_app_state: ResMut<NextState<AppState>>,
-) {
-    for (interaction, mut color, mut border_color) in &mut start_geme_button_query {
-        match *interaction {
-            Interaction::Pressed => {
-                despawn_start_button(&mut the_game_query, &mut commands);
-                next_app_state.set(AppState::Playing);
-            }
-            Interaction::Hovered => {
-                *color = HOVERED_BUTTON.into();
-                border_color.0 = Color::WHITE;
-            }
-            Interaction::None => {
-                *color = NORMAL_BUTTON.into();
-                border_color.0 = Color::BLACK;
-            }
-        }
-    }    
-}
@@ -0,0 +1,191 @@
+use std::fmt::Write;
+
+use bevy::math::Vec2;
+use bevy::prelude::*;
+
+use crate::input_map::{InputAction, InputMap};
+use crate::snake_extension::segment_poses;
+use crate::snake_model::SnakeModel;
+
+pub struct SvgExportPlugin;
+
+impl Plugin for SvgExportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, export_svg_pressed);
+    }
+}
+
+// "F2" (InputAction::ExportSvg) writes the creature as it is this frame to
+// creature_<trace_counter>.svg in the working directory: the raw trace, every body
+// segment where draw_nodes puts it, and arrows for the head and segment headings, each
+// in its own layer so they can be switched on and off in an SVG editor when a kink
+// needs explaining.
+
+const SVG_MARGIN: f32 = 40.0;
+const TRACE_COLOR: &str = "#888888";
+const SEGMENT_COLOR: &str = "#3070e0";
+const HEADING_COLOR: &str = "#e03070";
+const HEAD_COLOR: &str = "#d0a000";
+
+// SVG's y axis points down, the game's up, so every y is flipped on the way out.
+fn svg_point(pos: Vec2) -> (f32, f32) {
+    (pos.x, -pos.y)
+}
+
+fn arrow(svg: &mut String, from: Vec2, angle: f32, length: f32, color: &str) {
+    let (x1, y1) = svg_point(from);
+    let (x2, y2) = svg_point(from + Vec2::from_angle(angle) * length);
+    let _ = writeln!(svg, r#"    <line x1="{x1:.2}" y1="{y1:.2}" x2="{x2:.2}" y2="{y2:.2}" stroke="{color}" stroke-width="2" marker-end="url(#arrow)"/>"#);
+}
+
+fn layer_start(svg: &mut String, id: &str) {
+    let _ = writeln!(svg, r#"  <g id="{id}" inkscape:groupmode="layer" inkscape:label="{id}">"#);
+}
+
+pub fn creature_svg(snake: &SnakeModel) -> String {
+    let segments = segment_poses(snake);
+
+    let mut min = snake.head_pos - Vec2::splat(snake.head_radius);
+    let mut max = snake.head_pos + Vec2::splat(snake.head_radius);
+    for pos in snake.trace.iter().map(|item| item.pos) {
+        min = min.min(pos);
+        max = max.max(pos);
+    }
+    for segment in &segments {
+        min = min.min(segment.position - Vec2::splat(segment.radius));
+        max = max.max(segment.position + Vec2::splat(segment.radius));
+    }
+    min -= Vec2::splat(SVG_MARGIN);
+    max += Vec2::splat(SVG_MARGIN);
+    let size = max - min;
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:inkscape="http://www.inkscape.org/namespaces/inkscape" viewBox="{:.2} {:.2} {:.2} {:.2}">"#,
+        min.x, -max.y, size.x, size.y,
+    );
+    let _ = writeln!(svg, r#"  <defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="6" markerHeight="6" orient="auto"><path d="M0,0 L10,5 L0,10 z" fill="context-stroke"/></marker></defs>"#);
+
+    // head first, like draw_trace
+    layer_start(&mut svg, "trace");
+    let points: Vec<String> = std::iter::once(snake.head_pos)
+        .chain(snake.trace.iter().map(|item| item.pos))
+        .map(|pos| {
+            let (x, y) = svg_point(pos);
+            format!("{x:.2},{y:.2}")
+        })
+        .collect();
+    let _ = writeln!(svg, r#"    <polyline points="{}" fill="none" stroke="{TRACE_COLOR}" stroke-width="1"/>"#, points.join(" "));
+    for item in snake.trace.iter() {
+        let (x, y) = svg_point(item.pos);
+        let _ = writeln!(svg, r#"    <circle cx="{x:.2}" cy="{y:.2}" r="2" fill="{TRACE_COLOR}"><title>trace {}</title></circle>"#, item.index);
+    }
+    svg.push_str("  </g>\n");
+
+    layer_start(&mut svg, "segments");
+    for (i, segment) in segments.iter().enumerate() {
+        let (x, y) = svg_point(segment.position);
+        let _ = writeln!(
+            svg,
+            r#"    <circle cx="{x:.2}" cy="{y:.2}" r="{:.2}" fill="none" stroke="{SEGMENT_COLOR}" stroke-width="1.5"><title>segment {i}: angle {:.1} deg, trace {:.1} deg</title></circle>"#,
+            segment.radius,
+            segment.angle.to_degrees(),
+            segment.trace_direction.to_degrees(),
+        );
+    }
+    svg.push_str("  </g>\n");
+
+    // the smoothed facing of every segment, and the head's actual heading on top
+    layer_start(&mut svg, "headings");
+    for segment in &segments {
+        arrow(&mut svg, segment.position, segment.angle, segment.radius * 1.5, HEADING_COLOR);
+    }
+    let (head_x, head_y) = svg_point(snake.head_pos);
+    let _ = writeln!(svg, r#"    <circle cx="{head_x:.2}" cy="{head_y:.2}" r="{:.2}" fill="none" stroke="{HEAD_COLOR}" stroke-dasharray="4 4"/>"#, snake.head_radius);
+    arrow(&mut svg, snake.head_pos, snake.head_direction_angle, snake.head_radius, HEAD_COLOR);
+    svg.push_str("  </g>\n");
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+fn export_svg_pressed(keyboard_input: Res<ButtonInput<KeyCode>>, input_map: Res<InputMap>, snake_query: Query<&SnakeModel>) {
+    if !input_map.just_pressed(InputAction::ExportSvg, &keyboard_input) {
+        return;
+    }
+    for snake in &snake_query {
+        let path = format!("creature_{}.svg", snake.trace_counter);
+        match std::fs::write(&path, creature_svg(snake)) {
+            Ok(()) => info!("Wrote {path}"),
+            Err(e) => error!("Failed to write {path}: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::body_profile::lay_out_body_without_sprites;
+    use crate::snake_model::{SnakeMoveDirection, head_move_pure, snake_model_new};
+
+    // a creature that has swum up and then turned right, so the trace has a corner
+    fn cornered_snake() -> SnakeModel {
+        let mut snake = snake_model_new(0);
+        for _ in 0..30 {
+            head_move_pure(SnakeMoveDirection::Forward, 0.1, &mut snake);
+        }
+        snake.head_direction_angle = 0.0;
+        for _ in 0..10 {
+            head_move_pure(SnakeMoveDirection::Forward, 0.1, &mut snake);
+        }
+        // a bit past the last trace point, as the head usually is mid-run
+        head_move_pure(SnakeMoveDirection::Forward, 0.02, &mut snake);
+        lay_out_body_without_sprites(&mut snake);
+        snake
+    }
+
+    #[test]
+    fn one_pose_per_visible_segment_starting_at_the_head() {
+        let snake = cornered_snake();
+        let poses = segment_poses(&snake);
+        assert_eq!(poses.len(), snake.size as usize + 1);
+        assert_eq!(poses[0].position, snake.head_pos);
+        // segments behind the corner still face up the first leg
+        let tail = poses.last().unwrap();
+        assert!(tail.position.x < snake.head_pos.x);
+    }
+
+    #[test]
+    fn poses_follow_the_body_draw_nodes_lays_out() {
+        let mut snake = cornered_snake();
+        snake.body[2].distance_from_head += 5.0;
+        let poses = segment_poses(&snake);
+        assert_eq!(poses[2].distance_from_head, snake.body[2].distance_from_head);
+        assert_eq!(poses[2].radius, snake.body[2].radius);
+    }
+
+    #[test]
+    fn svg_has_a_layer_each_for_trace_segments_and_headings() {
+        let snake = cornered_snake();
+        let svg = creature_svg(&snake);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(!svg.contains("NaN"));
+        for layer in ["trace", "segments", "headings"] {
+            assert_eq!(svg.matches(&format!(r#"<g id="{layer}""#)).count(), 1);
+        }
+        assert_eq!(svg.matches("<title>trace ").count(), snake.trace.len());
+        assert_eq!(svg.matches("<title>segment ").count(), snake.size as usize + 1);
+        // one arrow per segment plus the head
+        assert_eq!(svg.matches("marker-end").count(), snake.size as usize + 2);
+    }
+
+    #[test]
+    fn y_is_flipped_for_svg() {
+        let mut snake = snake_model_new(0);
+        snake.head_pos = Vec2::new(10.0, 100.0);
+        let svg = creature_svg(&snake);
+        assert!(svg.contains(r#"cx="10.00" cy="-100.00""#));
+    }
+}
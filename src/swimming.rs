@@ -0,0 +1,139 @@
+use std::f32::consts::PI;
+use bevy::math::Vec2;
+
+use crate::creature_body_evolution::ease_smoothstep;
+use crate::snake_model::{SnakeModel, SnakeSpineNodeType};
+
+// Procedural swimming wiggle for the body segments, the same sine-wave-along-a-chain
+// idea as sprite.rs sprite_animate, but applied to the real creature. The offset is
+// purely visual: it's added to each sprite's position in draw_nodes (snake_extension.rs)
+// and never written back into the trace, so pruning and collisions keep using the centerline.
+
+// Lateral offset at the very tail, in multiples of node_radius, when swimming at REFERENCE_SWIM_SPEED.
+const BASE_SWIM_AMPLITUDE_FACTOR: f32 = 0.6;
+// Wiggle cycles per second at REFERENCE_SWIM_SPEED.
+const BASE_SWIM_FREQUENCY: f32 = 1.5;
+// Speed (matching snake_model_new's movement_speed) at which the base values above apply as-is.
+const REFERENCE_SWIM_SPEED: f32 = 150.0;
+// Cap so very fast creatures don't whip their tail around unrealistically.
+const MAX_SWIM_SPEED_RATIO: f32 = 2.0;
+// How many full sine waves fit along the body at any moment.
+const WAVES_PER_BODY: f32 = 1.0;
+
+// Per-tier character of the wiggle: (amplitude factor, frequency factor).
+// Small creatures flick quickly with a short stroke, big ones sweep slowly and wide.
+fn tier_swim_factors(tier: SnakeSpineNodeType) -> (f32, f32) {
+    match tier {
+        SnakeSpineNodeType::Small => (1.0, 1.2),
+        SnakeSpineNodeType::Medium => (1.2, 1.0),
+        SnakeSpineNodeType::Big => (1.5, 0.75),
+    }
+}
+
+fn swim_speed_ratio(speed: f32) -> f32 {
+    (speed.abs() / REFERENCE_SWIM_SPEED).min(MAX_SWIM_SPEED_RATIO)
+}
+
+// Peak lateral offset (at the tail) for the given speed and tier. Zero when standing still.
+pub fn swim_amplitude(speed: f32, tier: SnakeSpineNodeType, node_radius: f32) -> f32 {
+    let (amplitude_factor, _) = tier_swim_factors(tier);
+    BASE_SWIM_AMPLITUDE_FACTOR * amplitude_factor * node_radius * swim_speed_ratio(speed)
+}
+
+// Wiggle cycles per second for the given speed and tier. Zero when standing still.
+pub fn swim_frequency(speed: f32, tier: SnakeSpineNodeType) -> f32 {
+    let (_, frequency_factor) = tier_swim_factors(tier);
+    BASE_SWIM_FREQUENCY * frequency_factor * swim_speed_ratio(speed)
+}
+
+// How much of the amplitude applies at `normalized_pos` along the body
+// (0 = head, 1 = tail): nothing at the head, growing to full strength at the tail.
+pub fn swim_envelope(normalized_pos: f32) -> f32 {
+    ease_smoothstep(normalized_pos)
+}
+
+// Signed sideways distance of a segment from the centerline. The wave travels from
+// head to tail: a segment further back lags behind the head's phase.
+pub fn swim_lateral_offset(normalized_pos: f32, phase: f32, amplitude: f32) -> f32 {
+    let wave_angle = phase - normalized_pos * WAVES_PER_BODY * 2.0 * PI;
+    amplitude * swim_envelope(normalized_pos) * f32::sin(wave_angle)
+}
+
+// Moves `position` sideways by `offset`, perpendicular to `direction_angle` (radians).
+pub fn apply_lateral_offset(position: Vec2, direction_angle: f32, offset: f32) -> Vec2 {
+    position + Vec2::from_angle(direction_angle + PI / 2.0) * offset
+}
+
+// Advances the creature's wiggle phase by one frame, so the wave keeps flowing smoothly
+// even when speed (and therefore frequency) changes from frame to frame.
+pub fn advance_swim_phase(snake: &mut SnakeModel, delta_seconds: f32) {
+    let frequency = swim_frequency(snake.current_speed, snake.evolution_tier);
+    snake.swim_phase = (snake.swim_phase + 2.0 * PI * frequency * delta_seconds) % (2.0 * PI);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snake_model::snake_model_new;
+
+    fn assert_float_eq(a: f32, b: f32) {
+        let delta_max = 0.001;
+        let c = f32::abs(a - b);
+        assert!(c < delta_max)
+    }
+
+    #[test]
+    fn no_wiggle_when_standing_still() {
+        assert_eq!(swim_amplitude(0.0, SnakeSpineNodeType::Small, 10.0), 0.0);
+        assert_eq!(swim_frequency(0.0, SnakeSpineNodeType::Small), 0.0);
+    }
+
+    #[test]
+    fn amplitude_grows_with_speed_and_is_capped() {
+        let slow = swim_amplitude(75.0, SnakeSpineNodeType::Small, 10.0);
+        let normal = swim_amplitude(REFERENCE_SWIM_SPEED, SnakeSpineNodeType::Small, 10.0);
+        let very_fast = swim_amplitude(REFERENCE_SWIM_SPEED * 100.0, SnakeSpineNodeType::Small, 10.0);
+        assert!(slow < normal);
+        assert_float_eq(very_fast, normal * MAX_SWIM_SPEED_RATIO);
+    }
+
+    #[test]
+    fn bigger_tiers_sweep_wider_and_slower() {
+        let small_amplitude = swim_amplitude(REFERENCE_SWIM_SPEED, SnakeSpineNodeType::Small, 10.0);
+        let big_amplitude = swim_amplitude(REFERENCE_SWIM_SPEED, SnakeSpineNodeType::Big, 10.0);
+        assert!(big_amplitude > small_amplitude);
+        assert!(swim_frequency(REFERENCE_SWIM_SPEED, SnakeSpineNodeType::Big) < swim_frequency(REFERENCE_SWIM_SPEED, SnakeSpineNodeType::Small));
+    }
+
+    #[test]
+    fn head_never_moves_sideways() {
+        for phase in [0.0, 0.5, PI / 2.0, PI, 4.0] {
+            assert_eq!(swim_lateral_offset(0.0, phase, 10.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn tail_reaches_full_amplitude() {
+        // at the tail the wave is one full cycle behind the head, so phase PI/2 peaks there too
+        assert_float_eq(swim_lateral_offset(1.0, PI / 2.0, 10.0), 10.0);
+    }
+
+    #[test]
+    fn lateral_offset_is_perpendicular_to_direction() {
+        let moved = apply_lateral_offset(Vec2::new(5.0, 5.0), 0.0, 3.0); // facing +x, offset goes to +y
+        assert_float_eq(moved.x, 5.0);
+        assert_float_eq(moved.y, 8.0);
+    }
+
+    #[test]
+    fn phase_advances_only_while_moving() {
+        let mut snake = snake_model_new(0);
+        snake.current_speed = 0.0;
+        advance_swim_phase(&mut snake, 1.0);
+        assert_eq!(snake.swim_phase, 0.0);
+
+        snake.current_speed = REFERENCE_SWIM_SPEED;
+        advance_swim_phase(&mut snake, 0.1);
+        assert!(snake.swim_phase > 0.0);
+    }
+}
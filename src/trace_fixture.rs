@@ -0,0 +1,178 @@
+use std::path::{Path, PathBuf};
+
+use bevy::math::Vec2;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::input_map::{InputAction, InputMap};
+use crate::snake_extension::segment_poses;
+use crate::snake_model::SnakeModel;
+use crate::trace_position_calculator::calculate_node_pos_traced_on_distance_from_head;
+
+pub struct TraceFixturePlugin;
+
+impl Plugin for TraceFixturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, save_trace_fixture_pressed);
+    }
+}
+
+// Reproducing a body glitch: press "F9" (InputAction::SaveTraceFixture) while it's on
+// screen and the head pose, trace and every segment's distance_from_head are written to
+// test-data/trace_fixtures/ along with where calculate_node_pos_traced_on_distance_from_head
+// put each segment. Commit the file, and the fixture test below checks every one of them
+// on each `cargo test` - fix the glitch, then correct the positions in the file by hand.
+
+pub const TRACE_FIXTURE_DIR: &str = "test-data/trace_fixtures";
+
+// Positions are (x, y) pairs so the files stay easy to read and edit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceFixture {
+    pub head_pos: (f32, f32),
+    // radians, like SnakeModel.head_direction_angle
+    pub head_direction_angle: f32,
+    // newest first, like SnakeModel.trace
+    pub trace: Vec<(f32, f32)>,
+    pub segments: Vec<FixtureSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixtureSegment {
+    pub distance_from_head: f32,
+    // where the segment is expected to land
+    pub position: (f32, f32),
+}
+
+fn pair(pos: Vec2) -> (f32, f32) {
+    (pos.x, pos.y)
+}
+
+impl TraceFixture {
+    // Snapshots the creature, with segments where draw_nodes puts them this frame.
+    pub fn from_snake(snake: &SnakeModel) -> Self {
+        TraceFixture {
+            head_pos: pair(snake.head_pos),
+            head_direction_angle: snake.head_direction_angle,
+            trace: snake.trace.iter().map(|item| pair(item.pos)).collect(),
+            segments: segment_poses(snake).into_iter()
+                .map(|pose| FixtureSegment { distance_from_head: pose.distance_from_head, position: pair(pose.position) })
+                .collect(),
+        }
+    }
+
+    pub fn position_at(&self, distance_from_head: f32) -> Vec2 {
+        calculate_node_pos_traced_on_distance_from_head(
+            Vec2::from(self.head_pos),
+            self.head_direction_angle,
+            self.trace.iter().map(|&pos| Vec2::from(pos)),
+            distance_from_head,
+        ).position
+    }
+
+    // One line per segment that no longer lands where the fixture says. NaN is compared
+    // like any other value, so a fixture can pin down a glitch that produces one.
+    pub fn mismatches(&self, tolerance: f32) -> Vec<String> {
+        self.segments.iter().enumerate().filter_map(|(i, segment)| {
+            let expected = Vec2::from(segment.position);
+            let actual = self.position_at(segment.distance_from_head);
+            let close = |a: f32, b: f32| (a.is_nan() && b.is_nan()) || (a - b).abs() <= tolerance;
+            if close(actual.x, expected.x) && close(actual.y, expected.y) {
+                None
+            } else {
+                Some(format!("segment {i} at {}: expected {expected:?}, got {actual:?}", segment.distance_from_head))
+            }
+        }).collect()
+    }
+}
+
+pub fn parse_trace_fixture(text: &str) -> Result<TraceFixture, ron::error::SpannedError> {
+    ron::from_str(text)
+}
+
+pub fn save_trace_fixture(dir: &Path, name: &str, fixture: &TraceFixture) -> std::io::Result<PathBuf> {
+    let text = ron::ser::to_string_pretty(fixture, ron::ser::PrettyConfig::default())
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{name}.ron"));
+    std::fs::write(&path, text)?;
+    Ok(path)
+}
+
+fn save_trace_fixture_pressed(keyboard_input: Res<ButtonInput<KeyCode>>, input_map: Res<InputMap>, snake_query: Query<&SnakeModel>) {
+    if !input_map.just_pressed(InputAction::SaveTraceFixture, &keyboard_input) {
+        return;
+    }
+    for snake in &snake_query {
+        let name = format!("trace_{}", snake.trace_counter);
+        match save_trace_fixture(Path::new(TRACE_FIXTURE_DIR), &name, &TraceFixture::from_snake(snake)) {
+            Ok(path) => info!("Wrote {}", path.display()),
+            Err(e) => error!("Failed to save trace fixture {name}: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::body_profile::lay_out_body_without_sprites;
+    use crate::snake_model::{SnakeMoveDirection, head_move_pure, snake_model_new};
+
+    const TOLERANCE: f32 = 0.001;
+
+    #[test]
+    fn every_fixture_still_lays_out_the_same() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join(TRACE_FIXTURE_DIR);
+        let mut checked = 0;
+        let mut failures = vec![];
+        for entry in std::fs::read_dir(&dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().and_then(|e| e.to_str()) != Some("ron") {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path).unwrap();
+            let fixture = parse_trace_fixture(&text).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+            for mismatch in fixture.mismatches(TOLERANCE) {
+                failures.push(format!("{}: {mismatch}", path.display()));
+            }
+            checked += 1;
+        }
+        assert!(checked > 0, "no fixtures in {}", dir.display());
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn a_saved_snapshot_loads_back_and_matches() {
+        let mut snake = snake_model_new(0);
+        for _ in 0..20 {
+            head_move_pure(SnakeMoveDirection::Forward, 0.1, &mut snake);
+        }
+        snake.head_direction_angle = 0.3;
+        for _ in 0..5 {
+            head_move_pure(SnakeMoveDirection::Forward, 0.1, &mut snake);
+        }
+        head_move_pure(SnakeMoveDirection::Forward, 0.02, &mut snake);
+        lay_out_body_without_sprites(&mut snake);
+        let fixture = TraceFixture::from_snake(&snake);
+        assert_eq!(fixture.segments.len(), snake.size as usize + 1);
+        assert_eq!(fixture.trace.len(), snake.trace.len());
+
+        let dir = std::env::temp_dir().join(format!("sylvester_trace_fixture_{}", std::process::id()));
+        let path = save_trace_fixture(&dir, "snapshot", &fixture).unwrap();
+        let loaded = parse_trace_fixture(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(loaded, fixture);
+        assert!(loaded.mismatches(TOLERANCE).is_empty());
+    }
+
+    #[test]
+    fn moved_segments_are_reported() {
+        let mut fixture = TraceFixture {
+            head_pos: (0.0, 0.0),
+            head_direction_angle: std::f32::consts::PI / 2.0,
+            trace: vec![(0.0, -10.0), (0.0, -20.0)],
+            segments: vec![FixtureSegment { distance_from_head: 15.0, position: (0.0, -15.0) }],
+        };
+        assert!(fixture.mismatches(TOLERANCE).is_empty());
+        fixture.segments[0].position = (1.0, -15.0);
+        assert_eq!(fixture.mismatches(TOLERANCE).len(), 1);
+    }
+}
@@ -375,40 +375,6 @@ mod tests {
         
     }
 
-    #[test]
-    fn debug_strage_case() {
-        let head_pos = Vec2::new(0.0, 107.59321);
-        let trace = [
-            Vec2::new(0.0, 102.58502),
-            Vec2::new(0.0, 92.575264),
-            Vec2::new(0.0, 82.560165),
-            Vec2::new(0.0, 72.557556),
-            Vec2::new(0.0, 62.554504),
-            Vec2::new(0.0, 52.541656),
-            Vec2::new(0.0, 42.534496),
-            Vec2::new(0.0, 32.525833),
-            Vec2::new(0.0, 22.51635),
-            Vec2::new(0.0, 10.018846),
-            Vec2::new(0.0, -10.0),
-        ];
-
-        let expected_results = [
-            (107.59321, Vec2::new(0.0, -0.0)),
-        ];
-
-        for (distance_from_head, expected_pos) in expected_results {
-            let actual = calculate_node_pos_traced_on_distance_from_head(
-                head_pos, 
-                PI / 2.0,
-                LinkedList::from(trace).into_iter(), 
-                distance_from_head,
-            );
-
-            println!("!!!actual_pos: {:?}", actual.position);
-            println!("!!!expected_pos: {:?}", expected_pos);
-            assert_vec2_eq(actual.position, expected_pos);
-        }
-    }
 #[test]
 fn segment_distance_fraction_05_test() {
     let trace: [Vec2; 3] = [
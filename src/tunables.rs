@@ -0,0 +1,352 @@
+use std::fmt;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::snake_model::SnakeModel;
+
+pub struct TunablesPlugin;
+
+impl Plugin for TunablesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Tunables>();
+        app.init_asset::<Tunables>();
+        app.register_asset_loader(TunablesLoader);
+        app.add_systems(Startup, load_tunables);
+        app.add_systems(Update, (
+            tunables_asset_changed,
+            apply_creature_tunables.run_if(resource_changed::<Tunables>),
+        ).chain());
+    }
+}
+
+// The gameplay numbers designers tune, read from assets/game.tunables.ron. Systems read
+// the Tunables resource; it starts at Tunables::default() (the values the game shipped
+// with) and is replaced whenever the file finishes loading or is saved again - with the
+// "hot_reload" feature the asset server watches the file, so edits apply mid-run.
+// Every field has a default, so the file only needs the values being changed.
+
+pub const TUNABLES_PATH: &str = "game.tunables.ron";
+
+#[derive(Asset, TypePath, Resource, Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Tunables {
+    pub creature: CreatureTunables,
+    pub food: FoodTunables,
+    pub bound: BoundTunables,
+    pub tiers: TierTunables,
+    pub score: ScoreTunables,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreatureTunables {
+    // linear speed in meters per second
+    pub movement_speed: f32,
+    // how quickly the head turns, in degrees per second
+    pub rotation_speed_in_degrees: f32,
+    // minimum distance the head must travel before a new trace point is recorded
+    pub tracing_step: f32,
+    // how far the trace may be simplified away from the head's real path on straight stretches
+    pub trace_tolerance: f32,
+    // radius of the head's food-eating circle
+    pub head_radius: f32,
+    // size every run starts at; growth (food count, bound radius) is measured from it
+    pub starting_size: f32,
+}
+
+impl Default for CreatureTunables {
+    fn default() -> Self {
+        Self {
+            movement_speed: 150.0,
+            rotation_speed_in_degrees: 3.0,
+            tracing_step: 10.0,
+            trace_tolerance: 0.5,
+            head_radius: 50.0,
+            starting_size: 5.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FoodTunables {
+    // chance any given food spawn/respawn is poisonous
+    pub poison_chance: f64,
+    // size lost when eating poisonous food - a real setback, not devastating
+    pub poison_size_penalty: f32,
+    // floor so the creature never shrinks to nothing
+    pub min_snake_size: f32,
+    // food on screen at the starting size
+    pub base_food_count: usize,
+    // one more food for every this much growth
+    pub size_per_extra_food: f32,
+    // cap so entity count doesn't grow unbounded over a long session
+    pub max_food_count: usize,
+    // fraction of the boundary radius food spawns within, keeping it off the very edge
+    pub spawn_margin_factor: f32,
+    // how fast food wanders, per second
+    pub wander_speed: f32,
+}
+
+impl Default for FoodTunables {
+    fn default() -> Self {
+        Self {
+            poison_chance: 0.2,
+            poison_size_penalty: 3.0,
+            min_snake_size: 1.0,
+            base_food_count: 5,
+            size_per_extra_food: 5.0,
+            max_food_count: 20,
+            spawn_margin_factor: 0.6,
+            wander_speed: 60.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoundTunables {
+    // play-area radius at the starting size
+    pub base_radius: f32,
+    pub radius_growth_per_size: f32,
+}
+
+impl Default for BoundTunables {
+    fn default() -> Self {
+        Self {
+            base_radius: 500.0,
+            radius_growth_per_size: 15.0,
+        }
+    }
+}
+
+// Size (snake.size) at which the creature moves up to each evolution tier.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TierTunables {
+    pub medium_min_size: f32,
+    pub big_min_size: f32,
+}
+
+impl Default for TierTunables {
+    fn default() -> Self {
+        Self {
+            medium_min_size: 10.0,
+            big_min_size: 25.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScoreTunables {
+    // seconds after eating normal food in which the next one raises the combo multiplier
+    pub combo_window_seconds: f32,
+    // cap so a long streak can't make the score run away
+    pub max_combo_multiplier: i32,
+}
+
+impl Default for ScoreTunables {
+    fn default() -> Self {
+        Self {
+            combo_window_seconds: 2.0,
+            max_combo_multiplier: 5,
+        }
+    }
+}
+
+fn positive(value: f32) -> bool {
+    value.is_finite() && value > 0.0
+}
+
+fn not_negative(value: f32) -> bool {
+    value.is_finite() && value >= 0.0
+}
+
+fn fraction(value: f32) -> bool {
+    (0.0..=1.0).contains(&value)
+}
+
+impl Tunables {
+    // Catches values the game can't run with - a poison chance outside 0..=1 or a negative
+    // spawn margin panic in the rng, a zero tracing step never finishes retracing - so a typo
+    // while saving the file is rejected instead of crashing a run. Names the first bad field.
+    pub fn validate(&self) -> Result<(), String> {
+        let creature = &self.creature;
+        let food = &self.food;
+        let bound = &self.bound;
+        let tiers = &self.tiers;
+        let score = &self.score;
+        let checks = [
+            ("creature.movement_speed", not_negative(creature.movement_speed), "zero or more"),
+            ("creature.rotation_speed_in_degrees", creature.rotation_speed_in_degrees.is_finite(), "a number"),
+            ("creature.tracing_step", positive(creature.tracing_step), "more than zero"),
+            ("creature.trace_tolerance", not_negative(creature.trace_tolerance), "zero or more"),
+            ("creature.head_radius", positive(creature.head_radius), "more than zero"),
+            ("creature.starting_size", positive(creature.starting_size), "more than zero"),
+            ("food.poison_chance", (0.0..=1.0).contains(&food.poison_chance), "between 0 and 1"),
+            ("food.poison_size_penalty", not_negative(food.poison_size_penalty), "zero or more"),
+            ("food.min_snake_size", positive(food.min_snake_size), "more than zero"),
+            ("food.size_per_extra_food", positive(food.size_per_extra_food), "more than zero"),
+            ("food.spawn_margin_factor", fraction(food.spawn_margin_factor), "between 0 and 1"),
+            ("food.wander_speed", not_negative(food.wander_speed), "zero or more"),
+            ("bound.base_radius", positive(bound.base_radius), "more than zero"),
+            ("bound.radius_growth_per_size", not_negative(bound.radius_growth_per_size), "zero or more"),
+            ("tiers.medium_min_size", positive(tiers.medium_min_size), "more than zero"),
+            ("tiers.big_min_size", positive(tiers.big_min_size) && tiers.big_min_size > tiers.medium_min_size, "more than tiers.medium_min_size"),
+            ("score.combo_window_seconds", not_negative(score.combo_window_seconds), "zero or more"),
+            ("score.max_combo_multiplier", score.max_combo_multiplier >= 1, "1 or more"),
+        ];
+        match checks.iter().find(|(_, ok, _)| !ok) {
+            Some((field, _, expected)) => Err(format!("{field} must be {expected}")),
+            None => Ok(()),
+        }
+    }
+}
+
+pub fn parse_tunables(bytes: &[u8]) -> Result<Tunables, TunablesLoaderError> {
+    let tunables: Tunables = ron::de::from_bytes(bytes).map_err(TunablesLoaderError::Ron)?;
+    tunables.validate().map_err(TunablesLoaderError::Invalid)?;
+    Ok(tunables)
+}
+
+#[derive(Debug)]
+pub enum TunablesLoaderError {
+    Io(std::io::Error),
+    Ron(ron::error::SpannedError),
+    // parsed, but a value is out of range (see Tunables::validate)
+    Invalid(String),
+}
+
+impl fmt::Display for TunablesLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TunablesLoaderError::Io(e) => write!(f, "couldn't read tunables: {e}"),
+            TunablesLoaderError::Ron(e) => write!(f, "couldn't parse tunables: {e}"),
+            TunablesLoaderError::Invalid(e) => write!(f, "rejected tunables: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for TunablesLoaderError {}
+
+#[derive(Default)]
+struct TunablesLoader;
+
+impl AssetLoader for TunablesLoader {
+    type Asset = Tunables;
+    type Settings = ();
+    type Error = TunablesLoaderError;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Tunables, TunablesLoaderError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await.map_err(TunablesLoaderError::Io)?;
+        parse_tunables(&bytes)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tunables.ron"]
+    }
+}
+
+// Keeps the handle alive so the asset (and its hot reloads) stick around.
+#[derive(Resource)]
+struct TunablesHandle(Handle<Tunables>);
+
+fn load_tunables(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(TunablesHandle(asset_server.load(TUNABLES_PATH)));
+}
+
+// A file that fails to parse or validate never finishes loading (the asset server logs the
+// loader error), so no event arrives here and the values already in use stay.
+fn tunables_asset_changed(
+    mut asset_events: EventReader<AssetEvent<Tunables>>,
+    tunables_assets: Res<Assets<Tunables>>,
+    handle: Option<Res<TunablesHandle>>,
+    mut tunables: ResMut<Tunables>,
+) {
+    let Some(handle) = handle else { return; };
+    for event in asset_events.read() {
+        let loaded = matches!(event, AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } if *id == handle.0.id());
+        if !loaded {
+            continue;
+        }
+        if let Some(asset) = tunables_assets.get(&handle.0) {
+            tunables.set_if_neq(asset.clone());
+        }
+    }
+}
+
+// The creature copies its handling numbers when it spawns (snake_model_new); push new
+// values into the live one so a hot reload is felt straight away. Size is left alone.
+fn apply_creature_tunables(tunables: Res<Tunables>, mut snake_query: Query<&mut SnakeModel>) {
+    for mut snake in &mut snake_query {
+        apply_creature_handling(&mut snake, &tunables.creature);
+    }
+}
+
+pub fn apply_creature_handling(snake: &mut SnakeModel, creature: &CreatureTunables) {
+    snake.movement_speed = creature.movement_speed;
+    snake.rotation_speed_in_degrees = creature.rotation_speed_in_degrees;
+    snake.tracing_step = creature.tracing_step;
+    snake.trace_tolerance = creature.trace_tolerance;
+    snake.head_radius = creature.head_radius;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shipped_file_matches_the_defaults() {
+        let bytes = std::fs::read(format!("{}/assets/{TUNABLES_PATH}", env!("CARGO_MANIFEST_DIR"))).unwrap();
+        assert_eq!(parse_tunables(&bytes).unwrap(), Tunables::default());
+    }
+
+    #[test]
+    fn missing_fields_fall_back_to_defaults() {
+        let tunables = parse_tunables(b"(food: (poison_chance: 0.5))").unwrap();
+        assert_eq!(tunables.food.poison_chance, 0.5);
+        assert_eq!(tunables.food.max_food_count, FoodTunables::default().max_food_count);
+        assert_eq!(tunables.creature, CreatureTunables::default());
+    }
+
+    #[test]
+    fn bad_files_are_rejected() {
+        assert!(parse_tunables(b"(food: (poison_chance: \"lots\"))").is_err());
+    }
+
+    fn rejected_field(text: &str) -> String {
+        match parse_tunables(text.as_bytes()) {
+            Err(TunablesLoaderError::Invalid(message)) => message,
+            other => panic!("{text} wasn't rejected as invalid: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn values_that_would_crash_the_game_are_rejected() {
+        assert!(rejected_field("(food: (poison_chance: 1.5))").starts_with("food.poison_chance"));
+        assert!(rejected_field("(food: (poison_chance: -0.1))").starts_with("food.poison_chance"));
+        assert!(rejected_field("(food: (spawn_margin_factor: -0.6))").starts_with("food.spawn_margin_factor"));
+        assert!(rejected_field("(creature: (tracing_step: 0.0))").starts_with("creature.tracing_step"));
+        assert!(rejected_field("(food: (size_per_extra_food: 0.0))").starts_with("food.size_per_extra_food"));
+        assert!(rejected_field("(bound: (base_radius: -500.0))").starts_with("bound.base_radius"));
+        assert!(rejected_field("(tiers: (medium_min_size: 0.0))").starts_with("tiers.medium_min_size"));
+        assert!(rejected_field("(tiers: (medium_min_size: 30.0, big_min_size: 25.0))").starts_with("tiers.big_min_size"));
+        assert!(rejected_field("(tiers: (medium_min_size: 25.0, big_min_size: 25.0))").starts_with("tiers.big_min_size"));
+    }
+
+    #[test]
+    fn edge_values_are_still_allowed() {
+        let tunables = parse_tunables(b"(food: (poison_chance: 1.0, spawn_margin_factor: 0.0))").unwrap();
+        assert_eq!(tunables.food.poison_chance, 1.0);
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use super::theme::*;
+
+// What a menu button does when pressed. Widgets only report the press (MenuButtonPressed);
+// game_flow.rs carries it out.
+#[derive(Component, PartialEq, Debug, Clone, Copy)]
+pub enum MenuAction {
+    StartGame,
+    Resume,
+    Restart,
+    Settings,
+    CloseSettings,
+    QuitToMenu,
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MenuButtonPressed(pub MenuAction);
+
+// Rounded button with a text label, used for menu actions and as the base of the
+// toggle and selector arrows.
+pub fn spawn_button<'a>(parent: &'a mut ChildBuilder, asset_server: &AssetServer, label: &str, font_size: f32) -> EntityCommands<'a> {
+    let mut button = parent.spawn(ButtonBundle {
+        style: Style {
+            min_width: Val::Px(font_size * 1.5),
+            height: Val::Px(font_size * 1.6),
+            padding: UiRect::horizontal(Val::Px(font_size * 0.6)),
+            border: UiRect::all(Val::Px(font_size / 8.0)),
+            // horizontally center child text
+            justify_content: JustifyContent::Center,
+            // vertically center child text
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        border_color: BorderColor(NORMAL_BUTTON_BORDER),
+        border_radius: BorderRadius::MAX,
+        background_color: NORMAL_BUTTON.into(),
+        ..default()
+    });
+    button.with_children(|parent| {
+        parent.spawn(TextBundle::from_section(label, text_style(asset_server, font_size, TEXT_COLOR)));
+    });
+    button
+}
+
+pub fn spawn_menu_button(parent: &mut ChildBuilder, asset_server: &AssetServer, label: &str, action: MenuAction) -> Entity {
+    spawn_button(parent, asset_server, label, BUTTON_FONT_SIZE).insert(action).id()
+}
+
+type ButtonLook<'a> = (&'a Interaction, Option<&'a MenuAction>, &'a mut BackgroundColor, &'a mut BorderColor);
+
+// Hover/press styling for every Button, and a MenuButtonPressed for the ones with a MenuAction.
+pub(super) fn button_interaction(
+    mut button_query: Query<ButtonLook, (Changed<Interaction>, With<Button>)>,
+    mut pressed_events: EventWriter<MenuButtonPressed>,
+) {
+    for (interaction, action, mut color, mut border_color) in &mut button_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Some(action) = action {
+                    pressed_events.send(MenuButtonPressed(*action));
+                }
+            }
+            Interaction::Hovered => {
+                *color = HOVERED_BUTTON.into();
+                border_color.0 = HOVERED_BUTTON_BORDER;
+            }
+            Interaction::None => {
+                *color = NORMAL_BUTTON.into();
+                border_color.0 = NORMAL_BUTTON_BORDER;
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+pub mod theme;
+pub mod button;
+pub mod slider;
+pub mod toggle;
+pub mod selector;
+pub mod text_input;
+
+use bevy::prelude::*;
+
+// Reusable menu widgets. Each module has a spawn_* function that builds the widget under a
+// ChildBuilder, and the systems registered here handle Interaction/keyboard input for every
+// instance. Menus tag the returned entity with their own marker and react to the widget's
+// component changing (Changed<Slider>, Changed<Toggle>, ...) or to its event.
+pub struct UiWidgetsPlugin;
+
+impl Plugin for UiWidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<button::MenuButtonPressed>();
+        app.add_event::<text_input::TextInputSubmitted>();
+        app.add_systems(Update, (
+            button::button_interaction,
+            toggle::toggle_pressed.after(button::button_interaction),
+            selector::selector_arrow_pressed.after(button::button_interaction),
+            slider::slider_drag,
+            text_input::text_input_keys,
+        ));
+        app.add_systems(PostUpdate, (
+            toggle::toggle_display,
+            selector::selector_display,
+            slider::slider_display,
+            text_input::text_input_display,
+        ).before(bevy::ui::UiSystem::Layout));
+    }
+}
@@ -0,0 +1,95 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use super::button::spawn_button;
+use super::theme::*;
+
+// "< option >" picker over a fixed list of options. The arrows wrap around at either end;
+// menus watch Changed<ListSelector> and read `selected`.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ListSelector {
+    pub options: Vec<String>,
+    pub selected: usize,
+}
+
+impl ListSelector {
+    pub fn selected_option(&self) -> &str {
+        self.options.get(self.selected).map(String::as_str).unwrap_or("")
+    }
+}
+
+#[derive(Component)]
+pub(super) struct SelectorArrow {
+    selector: Entity,
+    step: i32,
+}
+
+#[derive(Component)]
+pub(super) struct SelectorValueText {
+    selector: Entity,
+}
+
+// Index `step` places away from `selected`, wrapping around a list of `len` options.
+pub fn cycle_index(selected: usize, len: usize, step: i32) -> usize {
+    if len == 0 {
+        return 0;
+    }
+    (selected as i64 + step as i64).rem_euclid(len as i64) as usize
+}
+
+pub fn spawn_list_selector<'a>(parent: &'a mut ChildBuilder, asset_server: &AssetServer, options: Vec<String>, selected: usize) -> EntityCommands<'a> {
+    let shown = options.get(selected).cloned().unwrap_or_default();
+    let mut selector = parent.spawn((row(), ListSelector { options, selected }));
+    let selector_entity = selector.id();
+    selector.with_children(|parent| {
+        spawn_button(parent, asset_server, "<", LABEL_FONT_SIZE).insert(SelectorArrow { selector: selector_entity, step: -1 });
+        parent.spawn((
+            label(asset_server, shown, LABEL_FONT_SIZE),
+            SelectorValueText { selector: selector_entity },
+        ));
+        spawn_button(parent, asset_server, ">", LABEL_FONT_SIZE).insert(SelectorArrow { selector: selector_entity, step: 1 });
+    });
+    selector
+}
+
+pub(super) fn selector_arrow_pressed(
+    arrow_query: Query<(&Interaction, &SelectorArrow), Changed<Interaction>>,
+    mut selector_query: Query<&mut ListSelector>,
+) {
+    for (interaction, arrow) in &arrow_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Ok(mut selector) = selector_query.get_mut(arrow.selector) {
+            selector.selected = cycle_index(selector.selected, selector.options.len(), arrow.step);
+        }
+    }
+}
+
+pub(super) fn selector_display(
+    selector_query: Query<&ListSelector, Changed<ListSelector>>,
+    mut text_query: Query<(&mut Text, &SelectorValueText)>,
+) {
+    for (mut text, value_text) in &mut text_query {
+        if let Ok(selector) = selector_query.get(value_text.selector) {
+            text.sections[0].value = selector.selected_option().to_string();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arrows_wrap_around_the_list() {
+        assert_eq!(cycle_index(2, 3, 1), 0);
+        assert_eq!(cycle_index(0, 3, -1), 2);
+        assert_eq!(cycle_index(1, 3, 1), 2);
+    }
+
+    #[test]
+    fn empty_list_stays_at_zero() {
+        assert_eq!(cycle_index(0, 0, 1), 0);
+    }
+}
@@ -0,0 +1,162 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+
+use super::theme::*;
+
+const TRACK_WIDTH: f32 = 220.0;
+const TRACK_HEIGHT: f32 = 14.0;
+
+// Horizontal slider between `min` and `max`, snapped to `step`. Click or drag along the
+// track to set it; menus watch Changed<Slider>.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Slider {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+}
+
+impl Slider {
+    // How far along the track `value` is, 0..=1.
+    pub fn fraction(&self) -> f32 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+}
+
+// The clickable part; Slider itself lives on the row around it.
+#[derive(Component)]
+pub(super) struct SliderTrack {
+    slider: Entity,
+}
+
+#[derive(Component)]
+pub(super) struct SliderFill {
+    slider: Entity,
+}
+
+#[derive(Component)]
+pub(super) struct SliderValueText {
+    slider: Entity,
+}
+
+// The value at `fraction` (0..=1) along the slider's track, snapped to its step.
+pub fn slider_value_at(slider: &Slider, fraction: f32) -> f32 {
+    let raw = slider.min + (slider.max - slider.min) * fraction.clamp(0.0, 1.0);
+    let snapped = if slider.step > 0.0 {
+        slider.min + ((raw - slider.min) / slider.step).round() * slider.step
+    } else {
+        raw
+    };
+    snapped.clamp(slider.min, slider.max)
+}
+
+// Sliders show their position as a percentage of the range, e.g. "75%".
+pub fn slider_label(slider: &Slider) -> String {
+    format!("{:.0}%", slider.fraction() * 100.0)
+}
+
+pub fn spawn_slider<'a>(parent: &'a mut ChildBuilder, asset_server: &AssetServer, slider: Slider) -> EntityCommands<'a> {
+    let mut row_commands = parent.spawn((row(), slider));
+    let slider_entity = row_commands.id();
+    row_commands.with_children(|parent| {
+        parent.spawn((
+            NodeBundle {
+                style: Style {
+                    width: Val::Px(TRACK_WIDTH),
+                    height: Val::Px(TRACK_HEIGHT),
+                    ..default()
+                },
+                background_color: TRACK_COLOR.into(),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+            SliderTrack { slider: slider_entity },
+        )).with_children(|track| {
+            track.spawn((
+                NodeBundle {
+                    style: Style {
+                        width: Val::Percent(slider.fraction() * 100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: FILL_COLOR.into(),
+                    border_radius: BorderRadius::MAX,
+                    ..default()
+                },
+                SliderFill { slider: slider_entity },
+            ));
+        });
+        parent.spawn((
+            label(asset_server, slider_label(&slider), LABEL_FONT_SIZE),
+            SliderValueText { slider: slider_entity },
+        ));
+    });
+    row_commands
+}
+
+// Follows the cursor while the track is held down.
+pub(super) fn slider_drag(
+    track_query: Query<(&Interaction, &RelativeCursorPosition, &SliderTrack)>,
+    mut slider_query: Query<&mut Slider>,
+) {
+    for (interaction, cursor, track) in &track_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(position) = cursor.normalized else { continue; };
+        let Ok(mut slider) = slider_query.get_mut(track.slider) else { continue; };
+        let value = slider_value_at(&slider, position.x);
+        if value != slider.value {
+            slider.value = value;
+        }
+    }
+}
+
+pub(super) fn slider_display(
+    slider_query: Query<&Slider, Changed<Slider>>,
+    mut fill_query: Query<(&mut Style, &SliderFill)>,
+    mut text_query: Query<(&mut Text, &SliderValueText)>,
+) {
+    for (mut style, fill) in &mut fill_query {
+        if let Ok(slider) = slider_query.get(fill.slider) {
+            style.width = Val::Percent(slider.fraction() * 100.0);
+        }
+    }
+    for (mut text, value_text) in &mut text_query {
+        if let Ok(slider) = slider_query.get(value_text.slider) {
+            text.sections[0].value = slider_label(slider);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn volume(value: f32) -> Slider {
+        Slider { value, min: 0.0, max: 1.0, step: 0.05 }
+    }
+
+    #[test]
+    fn value_snaps_to_the_step() {
+        assert!((slider_value_at(&volume(0.0), 0.51) - 0.5).abs() < 1e-5);
+        assert!((slider_value_at(&volume(0.0), 0.53) - 0.55).abs() < 1e-5);
+    }
+
+    #[test]
+    fn value_stays_in_range() {
+        assert_eq!(slider_value_at(&volume(0.5), -0.2), 0.0);
+        assert_eq!(slider_value_at(&volume(0.5), 1.3), 1.0);
+    }
+
+    #[test]
+    fn label_is_a_percentage_of_the_range() {
+        assert_eq!(slider_label(&volume(0.75)), "75%");
+    }
+}
@@ -0,0 +1,130 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use super::theme::*;
+
+// Short single-line text field, typed into straight from keyboard events. Menus only ever
+// show one at a time, so every TextInput on screen receives the typing. Enter (with some
+// text entered) sends TextInputSubmitted.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct TextInput {
+    pub value: String,
+    pub max_len: usize,
+    pub filter: TextFilter,
+}
+
+// Which characters a TextInput accepts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextFilter {
+    // letters only, stored uppercase - high score initials
+    UppercaseLetters,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct TextInputSubmitted {
+    pub entity: Entity,
+    pub value: String,
+}
+
+// Adds `c` to `value` if the filter allows it and there's room.
+pub fn push_char(value: &mut String, c: char, max_len: usize, filter: TextFilter) -> bool {
+    if value.chars().count() >= max_len {
+        return false;
+    }
+    match filter {
+        TextFilter::UppercaseLetters if c.is_ascii_alphabetic() => value.push(c.to_ascii_uppercase()),
+        _ => return false,
+    }
+    true
+}
+
+// The value padded with underscores for the empty slots, e.g. "AB_".
+pub fn padded_display(value: &str, max_len: usize) -> String {
+    let mut shown = value.to_string();
+    for _ in value.chars().count()..max_len {
+        shown.push('_');
+    }
+    shown
+}
+
+pub fn spawn_text_input<'a>(parent: &'a mut ChildBuilder, asset_server: &AssetServer, max_len: usize, filter: TextFilter) -> EntityCommands<'a> {
+    parent.spawn((
+        TextBundle::from_section(padded_display("", max_len), text_style(asset_server, 48.0, TEXT_COLOR)),
+        TextInput { value: String::new(), max_len, filter },
+    ))
+}
+
+// Letters type, Backspace deletes, Enter submits.
+pub fn text_input_keys(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut input_query: Query<(Entity, &mut TextInput)>,
+    mut submitted_events: EventWriter<TextInputSubmitted>,
+) {
+    if input_query.is_empty() {
+        keyboard_events.clear();
+        return;
+    }
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        for (entity, mut input) in &mut input_query {
+            let input = &mut *input;
+            match &event.logical_key {
+                Key::Character(text) => {
+                    for c in text.chars() {
+                        push_char(&mut input.value, c, input.max_len, input.filter);
+                    }
+                }
+                Key::Space => {
+                    push_char(&mut input.value, ' ', input.max_len, input.filter);
+                }
+                Key::Backspace => {
+                    input.value.pop();
+                }
+                Key::Enter if !input.value.is_empty() => {
+                    submitted_events.send(TextInputSubmitted { entity, value: input.value.clone() });
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub(super) fn text_input_display(mut input_query: Query<(&TextInput, &mut Text), Changed<TextInput>>) {
+    for (input, mut text) in &mut input_query {
+        text.sections[0].value = padded_display(&input.value, input.max_len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initials_are_uppercased_letters_only() {
+        let mut initials = String::new();
+        assert!(push_char(&mut initials, 'a', 3, TextFilter::UppercaseLetters));
+        assert!(!push_char(&mut initials, '1', 3, TextFilter::UppercaseLetters));
+        assert!(!push_char(&mut initials, ' ', 3, TextFilter::UppercaseLetters));
+        assert!(push_char(&mut initials, 'Z', 3, TextFilter::UppercaseLetters));
+        assert_eq!(initials, "AZ");
+    }
+
+    #[test]
+    fn input_stops_at_max_len() {
+        let mut initials = String::new();
+        for c in "ABCD".chars() {
+            push_char(&mut initials, c, 3, TextFilter::UppercaseLetters);
+        }
+        assert_eq!(initials, "ABC");
+    }
+
+    #[test]
+    fn empty_slots_show_as_underscores() {
+        assert_eq!(padded_display("A", 3), "A__");
+        assert_eq!(padded_display("ABC", 3), "ABC");
+    }
+}
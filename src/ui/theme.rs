@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+// Shared look for every menu: one font, one text color, one set of button colors.
+
+pub const FONT_PATH: &str = "MovistarTextRegular.ttf";
+
+pub const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+pub const HIGHLIGHT_TEXT_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+pub const NORMAL_BUTTON: Color = Color::srgb(0.15, 0.15, 0.15);
+pub const HOVERED_BUTTON: Color = Color::srgb(0.25, 0.25, 0.25);
+pub const NORMAL_BUTTON_BORDER: Color = Color::BLACK;
+pub const HOVERED_BUTTON_BORDER: Color = Color::WHITE;
+
+// slider track and the part of it that's filled up to the current value
+pub const TRACK_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.15);
+pub const FILL_COLOR: Color = Color::srgb(0.35, 0.75, 0.9);
+
+pub const TITLE_FONT_SIZE: f32 = 60.0;
+pub const BUTTON_FONT_SIZE: f32 = 40.0;
+pub const LABEL_FONT_SIZE: f32 = 24.0;
+
+pub fn text_style(asset_server: &AssetServer, font_size: f32, color: Color) -> TextStyle {
+    TextStyle {
+        font: asset_server.load(FONT_PATH),
+        font_size,
+        color,
+    }
+}
+
+pub fn label(asset_server: &AssetServer, value: impl Into<String>, font_size: f32) -> TextBundle {
+    TextBundle::from_section(value, text_style(asset_server, font_size, TEXT_COLOR))
+}
+
+// Centered column that menus stack their parts in.
+pub fn column() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(6.0),
+            margin: UiRect::top(Val::Px(20.0)),
+            ..default()
+        },
+        ..default()
+    }
+}
+
+// Label on the left, widget on the right - one line of a settings-style form.
+pub fn row() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(16.0),
+            ..default()
+        },
+        ..default()
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::ecs::system::EntityCommands;
+use bevy::prelude::*;
+
+use super::button::spawn_button;
+use super::theme::*;
+
+// On/off button. Pressing it flips `on`; menus watch Changed<Toggle>.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Toggle {
+    pub on: bool,
+}
+
+pub fn toggle_label(on: bool) -> &'static str {
+    if on { "On" } else { "Off" }
+}
+
+pub fn spawn_toggle<'a>(parent: &'a mut ChildBuilder, asset_server: &AssetServer, on: bool) -> EntityCommands<'a> {
+    let mut toggle = spawn_button(parent, asset_server, toggle_label(on), LABEL_FONT_SIZE);
+    toggle.insert(Toggle { on });
+    toggle
+}
+
+pub(super) fn toggle_pressed(mut toggle_query: Query<(&Interaction, &mut Toggle), Changed<Interaction>>) {
+    for (interaction, mut toggle) in &mut toggle_query {
+        if *interaction == Interaction::Pressed {
+            toggle.on = !toggle.on;
+        }
+    }
+}
+
+pub(super) fn toggle_display(toggle_query: Query<(&Toggle, &Children), Changed<Toggle>>, mut text_query: Query<&mut Text>) {
+    for (toggle, children) in &toggle_query {
+        for child in children {
+            if let Ok(mut text) = text_query.get_mut(*child) {
+                text.sections[0].value = toggle_label(toggle.on).to_string();
+            }
+        }
+    }
+}